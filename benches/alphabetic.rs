@@ -0,0 +1,100 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use korrektor::uzbek::alphabetic::{self, UzbekCollator};
+
+#[path = "../tests/baseline/mod.rs"]
+mod baseline;
+
+const WORDS: &[&str] = &[
+    "O‘zbekiston",
+    "respublikasi",
+    "elektromobil",
+    "ishlab",
+    "chiqarish",
+    "bo‘yicha",
+    "yangi",
+    "loyihani",
+    "taqdim",
+    "etdi",
+    "Toshkentda",
+    "ko‘rgazmasi",
+    "g‘alaba",
+    "shahar",
+    "choyxona",
+    "ўзбек",
+    "чиройли",
+    "қишлоқ",
+    "ғалаба",
+    "ҳаёт",
+];
+
+// letters of the endings, without the ones that make a digraph
+const ENDINGS: &[u8] = b"abdefgijklmnpqrtuvxz";
+
+// a glossary of the given size made of the words above with different endings,
+// all of the same length so that no word starts with another and the baseline
+// sort does not panic
+fn glossary(size: usize) -> String {
+    (0..size)
+        .map(|i| {
+            let ending: String = (0..4)
+                .map(|place| ENDINGS[i / ENDINGS.len().pow(place) % ENDINGS.len()] as char)
+                .collect();
+            format!("{}{}", WORDS[i % WORDS.len()], ending)
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn sort(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sort");
+
+    for size in [1_000, 10_000, 100_000] {
+        let text = glossary(size);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &text, |b, text| {
+            b.iter(|| alphabetic::sort(black_box(text)))
+        });
+    }
+
+    group.finish();
+}
+
+// the bubble sort that `sort` replaced, too slow for the larger glossaries
+fn baseline(c: &mut Criterion) {
+    let mut group = c.benchmark_group("baseline");
+    group.sample_size(10);
+
+    let size = 1_000;
+    let text = glossary(size);
+    group.bench_with_input(BenchmarkId::from_parameter(size), &text, |b, text| {
+        b.iter(|| baseline::alphabetic::sort(black_box(text)))
+    });
+
+    group.finish();
+}
+
+fn collator(c: &mut Criterion) {
+    let mut group = c.benchmark_group("collator");
+    let text = glossary(10_000);
+    let words: Vec<&str> = text.split(' ').collect();
+    let collator = UzbekCollator::new();
+
+    group.bench_function("sort_by", |b| {
+        b.iter(|| {
+            let mut words = words.clone();
+            words.sort_by(|a, b| collator.compare(a, b));
+            words
+        })
+    });
+    group.bench_function("sort_by_cached_key", |b| {
+        b.iter(|| {
+            let mut words = words.clone();
+            words.sort_by_cached_key(|word| alphabetic::collation_key(word));
+            words
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, sort, baseline, collator);
+criterion_main!(benches);
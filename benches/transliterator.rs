@@ -0,0 +1,62 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use korrektor::uzbek::transliterator::{self, Transliterator};
+
+#[path = "../tests/baseline/mod.rs"]
+mod baseline;
+
+const LATIN: &str = "O‘zbekiston Respublikasi Prezidenti elektromobil ishlab chiqarish \
+    bo‘yicha yangi loyihani taqdim etdi. Loyiha doirasida 2023-yil 12-yanvar kuni \
+    Toshkentda “Yangi avlod” ko‘rgazmasi bo‘lib o‘tdi, unda aviadvigatel va \
+    mikrokalkulyator kabi mahsulotlar namoyish etildi.";
+
+const CYRILLIC: &str = "Ўзбекистон Республикаси Президенти электромобиль ишлаб чиқариш \
+    бўйича янги лойиҳани тақдим этди. Лойиҳа доирасида 2023 йил 12 январь куни \
+    Тошкентда «Янги авлод» кўргазмаси бўлиб ўтди, унда авиадвигатель ва \
+    микрокалькулятор каби маҳсулотлар намойиш этилди.";
+
+fn to_cyrillic(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_cyrillic");
+
+    // what every call used to cost: all rule tables compiled from scratch
+    group.bench_function("baseline", |b| {
+        b.iter(|| baseline::transliterator::to_cyrillic(black_box(LATIN).to_string()))
+    });
+
+    let engine = Transliterator::new();
+    group.bench_function("precompiled", |b| {
+        b.iter(|| engine.to_cyrillic(black_box(LATIN)))
+    });
+    group.bench_function("free_function", |b| {
+        b.iter(|| transliterator::to_cyrillic(black_box(LATIN).to_string()))
+    });
+    // the same rules with edits collected for the alignment
+    group.bench_function("aligned", |b| {
+        b.iter(|| engine.to_cyrillic_aligned(black_box(LATIN)))
+    });
+
+    group.finish();
+}
+
+fn to_latin(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_latin");
+
+    group.bench_function("baseline", |b| {
+        b.iter(|| baseline::transliterator::to_latin(black_box(CYRILLIC).to_string()))
+    });
+
+    let engine = Transliterator::new();
+    group.bench_function("precompiled", |b| {
+        b.iter(|| engine.to_latin(black_box(CYRILLIC)))
+    });
+    group.bench_function("free_function", |b| {
+        b.iter(|| transliterator::to_latin(black_box(CYRILLIC).to_string()))
+    });
+    group.bench_function("aligned", |b| {
+        b.iter(|| engine.to_latin_aligned(black_box(CYRILLIC)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, to_cyrillic, to_latin);
+criterion_main!(benches);
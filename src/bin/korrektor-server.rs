@@ -0,0 +1,50 @@
+//! Self-hosted korrektor HTTP API.
+//!
+//! Usage: `korrektor-server [--address HOST:PORT] [--max-body-size BYTES] [--workers N]`
+use korrektor::server::{self, ServerConfig};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let config = match parse_args(std::env::args().skip(1)) {
+        Ok(config) => config,
+        Err(message) => {
+            eprintln!("korrektor-server: {message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    eprintln!("korrektor-server: listening on http://{}", config.address);
+
+    match server::serve(&config) {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("korrektor-server: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<ServerConfig, String> {
+    let mut config = ServerConfig::default();
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for {arg}"));
+
+        match arg.as_str() {
+            "--address" => config.address = value()?,
+            "--max-body-size" => {
+                config.max_body_size = value()?
+                    .parse()
+                    .map_err(|_| String::from("--max-body-size should be a number of bytes"))?
+            }
+            "--workers" => {
+                config.workers = value()?
+                    .parse()
+                    .map_err(|_| String::from("--workers should be a number"))?
+            }
+            _ => return Err(format!("unknown argument: {arg}")),
+        }
+    }
+
+    Ok(config)
+}
@@ -7,6 +7,8 @@ pub enum KorrektorError {
     InvalidChar(char),
     InvalidNumber(String, String),
     NumberOverflow(String, String),
+    InvalidAlphabet(String),
+    InvalidDictionary(usize, String),
 }
 
 impl Display for KorrektorError {
@@ -17,7 +19,15 @@ impl Display for KorrektorError {
             KorrektorError::InvalidNumber(number, message) =>
                 write!(f, "{message}: {number}!"),
             KorrektorError::NumberOverflow(number, message) =>
-                write!(f, "{message}: {number}")
+                write!(f, "{message}: {number}"),
+            KorrektorError::InvalidAlphabet(alphabet) =>
+                write!(f, "Invalid alphabet: \"{alphabet}\"! Only \"lat\" and \"cyr\" are supported."),
+            KorrektorError::InvalidDictionary(0, message) =>
+                write!(f, "Invalid dictionary: {message}!"),
+            KorrektorError::InvalidDictionary(line, message) =>
+                write!(f, "Invalid dictionary on line {line}: {message}!")
         }
     }
 }
+
+impl std::error::Error for KorrektorError {}
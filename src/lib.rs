@@ -14,8 +14,11 @@
 //! regex and pcre crates in order to perform natural
 //! language processing.
 //!
-//! # Upcoming
-//! Public API with transliteration and correction functionality.
+//! # Self-hosting
+//! The `server` feature provides a small HTTP service ([`server`])
+//! with the same functionality exposed as a JSON API.
 pub mod error;
+#[cfg(feature = "server")]
+pub mod server;
 pub mod utils;
 pub mod uzbek;
@@ -0,0 +1,302 @@
+//! Command-line interface for korrektor.
+//!
+//! Every subcommand reads the text from the given files
+//! (or standard input) and writes the result to standard output.
+use clap::{Parser, Subcommand};
+use korrektor::error::KorrektorError;
+use korrektor::utils::{duplicates, frequency};
+use korrektor::uzbek::{alphabetic, corrector, number, tokenize, transliterator, Alphabet};
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Debug, Parser)]
+#[command(name = "korrektor", version, about = "Uzbek language text processing")]
+struct Cli {
+    /// Print the result as JSON
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Transliterate text into the given alphabet
+    Translit {
+        /// Target alphabet: "cyr" or "lat"
+        #[arg(long)]
+        to: Alphabet,
+        #[command(flatten)]
+        input: Input,
+    },
+    /// Correct common spelling mistakes
+    Correct {
+        #[command(flatten)]
+        input: Input,
+    },
+    /// Check spelling and list suggestions for misspelled words
+    Spell {
+        /// Alphabet of the text: "cyr" or "lat"
+        #[arg(long, default_value_t = Alphabet::Latin)]
+        lang: Alphabet,
+        #[command(flatten)]
+        input: Input,
+    },
+    /// Sort words alphabetically
+    Sort {
+        /// Sort in descending order
+        #[arg(long)]
+        descending: bool,
+        /// Keep only the first of the same words
+        #[arg(long)]
+        unique: bool,
+        /// Print every word on its own line
+        #[arg(long)]
+        lines: bool,
+        #[command(flatten)]
+        input: Input,
+    },
+    /// Split words by syllables
+    Syllables {
+        #[command(flatten)]
+        input: Input,
+    },
+    /// Convert numbers into words
+    Numbers {
+        /// Alphabet of the words: "cyr" or "lat", the one of the text by default
+        #[arg(long)]
+        lang: Option<Alphabet>,
+        /// Read Roman numerals before words like asr or bob as ordinals
+        #[arg(long)]
+        roman: bool,
+        #[command(flatten)]
+        input: Input,
+    },
+    /// Remove duplicated words
+    Dedup {
+        #[command(flatten)]
+        input: Input,
+    },
+    /// Count occurrences of words
+    Freq {
+        #[command(flatten)]
+        input: Input,
+    },
+}
+
+#[derive(Debug, clap::Args)]
+struct Input {
+    /// Files to read, standard input is used if none (or "-") given
+    files: Vec<PathBuf>,
+}
+
+impl Command {
+    fn input(&self) -> &Input {
+        match self {
+            Command::Translit { input, .. }
+            | Command::Correct { input }
+            | Command::Spell { input, .. }
+            | Command::Sort { input, .. }
+            | Command::Syllables { input }
+            | Command::Numbers { input, .. }
+            | Command::Dedup { input }
+            | Command::Freq { input } => input,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let text = match read_input(cli.command.input()) {
+        Ok(text) => text,
+        Err(error) => {
+            eprintln!("korrektor: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(&cli.command, &text, cli.json) {
+        Ok(output) => {
+            let mut stdout = io::stdout().lock();
+            let newline = if output.ends_with('\n') { "" } else { "\n" };
+            match write!(stdout, "{output}{newline}") {
+                Ok(_) => ExitCode::SUCCESS,
+                Err(error) => {
+                    eprintln!("korrektor: {error}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Err(error) => {
+            eprintln!("korrektor: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn read_input(input: &Input) -> io::Result<String> {
+    let mut text = String::new();
+
+    if input.files.is_empty() {
+        io::stdin().read_to_string(&mut text)?;
+    }
+
+    for file in &input.files {
+        if file.as_os_str() == "-" {
+            io::stdin().read_to_string(&mut text)?;
+        } else {
+            let content = std::fs::read_to_string(file).map_err(|error| {
+                io::Error::new(error.kind(), format!("{}: {error}", file.display()))
+            })?;
+            text.push_str(&content);
+        }
+    }
+
+    Ok(text)
+}
+
+fn run(command: &Command, text: &str, json: bool) -> Result<String, KorrektorError> {
+    let output = match command {
+        Command::Translit { to, .. } => transliterator::to(text.to_string(), *to),
+        Command::Correct { .. } => corrector::correct(text),
+        Command::Spell { lang, .. } => {
+            let words = corrector::get_correction_suggestions(text, *lang);
+
+            if json {
+                return Ok(to_json(&words));
+            }
+
+            words
+                .iter()
+                .map(|word| {
+                    format!(
+                        "{}\t{}\t{}",
+                        word.position,
+                        word.misspelled,
+                        word.suggestions.join(", ")
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n")
+        }
+        Command::Sort {
+            descending,
+            unique,
+            lines,
+            ..
+        } => {
+            let options = alphabetic::SortOptions {
+                descending: *descending,
+                unique: *unique,
+                separator: if *lines {
+                    alphabetic::Separator::Newline
+                } else {
+                    alphabetic::Separator::Space
+                },
+                ..alphabetic::SortOptions::default()
+            };
+
+            alphabetic::sort_with(text, &options)?
+        }
+        Command::Syllables { .. } => tokenize::split_text(text),
+        Command::Numbers { lang, roman, .. } => {
+            let options = number::NumberOptions {
+                alphabet: *lang,
+                roman_numerals: *roman,
+                ..number::NumberOptions::default()
+            };
+
+            number::numbers_to_word_with(text, &options)?
+        }
+        Command::Dedup { .. } => duplicates::remove(text),
+        Command::Freq { .. } => {
+            let counts = frequency::count(text);
+
+            if json {
+                return Ok(to_json(&counts));
+            }
+
+            // most frequent words first
+            let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+            counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+            counts
+                .iter()
+                .map(|(word, count)| format!("{word}\t{count}"))
+                .collect::<Vec<String>>()
+                .join("\n")
+        }
+    };
+
+    if json {
+        Ok(to_json(&output))
+    } else {
+        Ok(output)
+    }
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_string(value).expect("korrektor results are always serializable")
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    fn command(args: &[&str]) -> Command {
+        let args = [&["korrektor"], args].concat();
+        Cli::parse_from(args).command
+    }
+
+    #[test]
+    fn parse_test() {
+        assert!(Cli::try_parse_from(["korrektor", "translit", "--to", "cyrl"]).is_err());
+        assert!(Cli::try_parse_from(["korrektor", "translit"]).is_err());
+
+        let cli = Cli::parse_from(["korrektor", "freq", "--json", "a.txt", "-"]);
+        assert!(cli.json);
+        assert_eq!(
+            cli.command.input().files,
+            vec![PathBuf::from("a.txt"), PathBuf::from("-")]
+        );
+    }
+
+    #[test]
+    fn run_test() {
+        let translit = command(&["translit", "--to", "cyr"]);
+        assert_eq!(run(&translit, "g'ozal", false).unwrap(), "ғозал");
+        assert_eq!(run(&translit, "g'ozal", true).unwrap(), "\"ғозал\"");
+
+        let numbers = command(&["numbers"]);
+        assert_eq!(run(&numbers, "12", false).unwrap(), "o‘n ikki");
+        let numbers = command(&["numbers", "--lang", "cyr"]);
+        assert_eq!(run(&numbers, "12", false).unwrap(), "ўн икки");
+        let numbers = command(&["numbers", "--roman"]);
+        assert_eq!(run(&numbers, "V bob", false).unwrap(), "beshinchi bob");
+
+        let sort = command(&["sort"]);
+        assert_eq!(run(&sort, "nok olma nok", false).unwrap(), "nok nok olma");
+        let sort = command(&["sort", "--descending", "--unique", "--lines"]);
+        assert_eq!(run(&sort, "nok olma nok", false).unwrap(), "olma\nnok");
+
+        let syllables = command(&["syllables"]);
+        assert_eq!(run(&syllables, "chiroyli", false).unwrap(), "chi-roy-li");
+    }
+
+    #[test]
+    fn freq_test() {
+        let freq = command(&["freq"]);
+        assert_eq!(
+            run(&freq, "salom xato salom", false).unwrap(),
+            "salom\t2\nxato\t1"
+        );
+
+        let json: std::collections::HashMap<String, usize> =
+            serde_json::from_str(&run(&freq, "salom xato salom", true).unwrap()).unwrap();
+        assert_eq!(json["salom"], 2);
+        assert_eq!(json["xato"], 1);
+    }
+}
@@ -0,0 +1,396 @@
+//! HTTP server exposing korrektor functionality as a JSON API.
+//!
+//! Mirrors the endpoints of <https://korrektor.uz> so the
+//! functionality can be self-hosted. Every endpoint accepts
+//! a `POST` request with a JSON body and answers with JSON.
+//!
+//! | Endpoint          | Body                            | Result                     |
+//! |-------------------|---------------------------------|----------------------------|
+//! | `/transliterate`  | `{"text", "alphabet"}`          | `{"result": "..."}`        |
+//! | `/correct`        | `{"text"}`                      | `{"result": "..."}`        |
+//! | `/suggestions`    | `{"text", "alphabet"}`          | list of misspelled words   |
+//! | `/sort`           | `{"text", "strict"}`            | `{"result": "..."}`        |
+//! | `/syllables`      | `{"text"}`                      | `{"result": "..."}`        |
+//! | `/numbers`        | `{"text", "alphabet", "roman"}` | `{"result": "..."}`        |
+//!
+//! Only `"text"` is required by `/numbers`: `"alphabet"` is the one of the text
+//! by default and `"roman"` (reading Roman numerals) is `false` by default.
+//! `"strict"` of `/sort` (accepting only the letters of the alphabet) is `false` by default.
+//!
+//! Errors are returned as `{"error": "<kind>", "message": "..."}`,
+//! korrektor errors additionally carry the serialized error in `"details"`.
+use crate::error::KorrektorError;
+use crate::uzbek::{alphabetic, corrector, number, tokenize, transliterator, Alphabet};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::io::Read;
+use std::sync::Arc;
+
+/// Settings of the HTTP server.
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    /// Address to listen on, `127.0.0.1:8080` by default.
+    pub address: String,
+    /// Largest accepted request body in bytes, 1 MiB by default.
+    pub max_body_size: usize,
+    /// Number of threads handling requests, 4 by default.
+    pub workers: usize,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        ServerConfig {
+            address: String::from("127.0.0.1:8080"),
+            max_body_size: 1024 * 1024,
+            workers: 4,
+        }
+    }
+}
+
+/// Response of the API: HTTP status code and JSON body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TextRequest {
+    text: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AlphabetRequest {
+    text: String,
+    alphabet: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SortRequest {
+    text: String,
+    #[serde(default)]
+    strict: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct NumbersRequest {
+    text: String,
+    #[serde(default)]
+    alphabet: Option<String>,
+    #[serde(default)]
+    roman: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct TextResponse {
+    result: String,
+}
+
+enum ApiError {
+    Korrektor(KorrektorError),
+    BadRequest(String),
+    NotFound(String),
+    MethodNotAllowed(String),
+    PayloadTooLarge(usize),
+    Internal(String),
+}
+
+impl From<KorrektorError> for ApiError {
+    fn from(error: KorrektorError) -> Self {
+        ApiError::Korrektor(error)
+    }
+}
+
+impl ApiError {
+    fn into_response(self) -> Response {
+        let (status, body) = match self {
+            ApiError::Korrektor(error) => {
+                let details = serde_json::to_value(&error).unwrap_or(Value::Null);
+                // externally tagged enum: {"InvalidChar": "x"}
+                let kind = match &details {
+                    Value::Object(map) => map.keys().next().cloned(),
+                    Value::String(kind) => Some(kind.clone()),
+                    _ => None,
+                };
+
+                let body = json!({
+                    "error": kind.unwrap_or_else(|| String::from("KorrektorError")),
+                    "message": error.to_string(),
+                    "details": details,
+                });
+                (422, body)
+            }
+            ApiError::BadRequest(message) => {
+                (400, json!({"error": "BadRequest", "message": message}))
+            }
+            ApiError::NotFound(path) => (
+                404,
+                json!({"error": "NotFound", "message": format!("No such endpoint: {path}")}),
+            ),
+            ApiError::MethodNotAllowed(method) => (
+                405,
+                json!({"error": "MethodNotAllowed", "message": format!("Only POST is supported, got {method}")}),
+            ),
+            ApiError::PayloadTooLarge(limit) => (
+                413,
+                json!({"error": "PayloadTooLarge", "message": format!("Request body exceeds {limit} bytes")}),
+            ),
+            ApiError::Internal(message) => (500, json!({"error": "Internal", "message": message})),
+        };
+
+        Response {
+            status,
+            body: body.to_string(),
+        }
+    }
+}
+
+/// Handles a single API request.
+///
+/// Independent of the transport, [`serve`] passes every
+/// HTTP request it receives through this function.
+///
+/// # Example
+/// ```rust
+/// use korrektor::server;
+///
+/// let body = r#"{"text": "g'ozal", "alphabet": "cyr"}"#;
+/// let response = server::handle("POST", "/transliterate", body.as_bytes());
+///
+/// assert_eq!(response.status, 200);
+/// assert_eq!(response.body, r#"{"result":"ғозал"}"#);
+/// ```
+pub fn handle(method: &str, path: &str, body: &[u8]) -> Response {
+    match route(method, path, body) {
+        Ok(body) => Response { status: 200, body },
+        Err(error) => error.into_response(),
+    }
+}
+
+fn route(method: &str, path: &str, body: &[u8]) -> Result<String, ApiError> {
+    // ignore query string and trailing slash
+    let path = path.split('?').next().unwrap_or_default();
+    let path = path.strip_suffix('/').unwrap_or(path);
+
+    let endpoints = [
+        "/transliterate",
+        "/correct",
+        "/suggestions",
+        "/sort",
+        "/syllables",
+        "/numbers",
+    ];
+    if !endpoints.contains(&path) {
+        return Err(ApiError::NotFound(path.to_string()));
+    }
+    if !method.eq_ignore_ascii_case("POST") {
+        return Err(ApiError::MethodNotAllowed(method.to_string()));
+    }
+
+    let result = match path {
+        "/transliterate" => {
+            let request: AlphabetRequest = parse(body)?;
+            let alphabet: Alphabet = request.alphabet.parse()?;
+
+            transliterator::to(request.text, alphabet)
+        }
+        "/suggestions" => {
+            let request: AlphabetRequest = parse(body)?;
+            let alphabet: Alphabet = request.alphabet.parse()?;
+
+            let words = corrector::get_correction_suggestions(&request.text, alphabet);
+            return to_json(&words);
+        }
+        "/correct" => corrector::correct(&parse::<TextRequest>(body)?.text),
+        "/sort" => {
+            let request: SortRequest = parse(body)?;
+            let options = alphabetic::SortOptions {
+                strict: request.strict,
+                ..alphabetic::SortOptions::default()
+            };
+
+            alphabetic::sort_with(&request.text, &options)?
+        }
+        "/syllables" => tokenize::split_text(&parse::<TextRequest>(body)?.text),
+        "/numbers" => {
+            let request: NumbersRequest = parse(body)?;
+            let options = number::NumberOptions {
+                alphabet: request.alphabet.map(|a| a.parse()).transpose()?,
+                roman_numerals: request.roman,
+                ..number::NumberOptions::default()
+            };
+
+            number::numbers_to_word_with(&request.text, &options)?
+        }
+        _ => unreachable!("endpoints are checked above"),
+    };
+
+    to_json(&TextResponse { result })
+}
+
+fn parse<'a, T: Deserialize<'a>>(body: &'a [u8]) -> Result<T, ApiError> {
+    serde_json::from_slice(body).map_err(|error| ApiError::BadRequest(error.to_string()))
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<String, ApiError> {
+    serde_json::to_string(value).map_err(|error| ApiError::Internal(error.to_string()))
+}
+
+/// Starts the HTTP server and blocks the current thread serving requests.
+///
+/// # Errors
+/// Returns an error if the server could not listen on the configured address.
+pub fn serve(config: &ServerConfig) -> std::io::Result<()> {
+    let server = tiny_http::Server::http(&config.address)
+        .map_err(|error| std::io::Error::new(std::io::ErrorKind::AddrNotAvailable, error))?;
+    let server = Arc::new(server);
+
+    let workers: Vec<_> = (0..config.workers.max(1))
+        .map(|_| {
+            let server = Arc::clone(&server);
+            let max_body_size = config.max_body_size;
+
+            std::thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    respond(request, max_body_size);
+                }
+            })
+        })
+        .collect();
+
+    for worker in workers {
+        let _ = worker.join();
+    }
+
+    Ok(())
+}
+
+fn respond(mut request: tiny_http::Request, max_body_size: usize) {
+    let response = match read_body(&mut request, max_body_size) {
+        Ok(body) => handle(request.method().as_str(), request.url(), &body),
+        Err(error) => error.into_response(),
+    };
+
+    let header = tiny_http::Header::from_bytes("Content-Type", "application/json; charset=utf-8")
+        .expect("static header is valid");
+    let http_response = tiny_http::Response::from_string(response.body)
+        .with_status_code(response.status)
+        .with_header(header);
+
+    // the client may have gone away, nothing to do about it
+    let _ = request.respond(http_response);
+}
+
+fn read_body(request: &mut tiny_http::Request, max_body_size: usize) -> Result<Vec<u8>, ApiError> {
+    if request.body_length().unwrap_or(0) > max_body_size {
+        return Err(ApiError::PayloadTooLarge(max_body_size));
+    }
+
+    // the body length may be unknown (chunked encoding), never read more than allowed
+    let mut body = vec![];
+    request
+        .as_reader()
+        .take(max_body_size as u64 + 1)
+        .read_to_end(&mut body)
+        .map_err(|error| ApiError::BadRequest(error.to_string()))?;
+
+    if body.len() > max_body_size {
+        return Err(ApiError::PayloadTooLarge(max_body_size));
+    }
+
+    Ok(body)
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    fn json(response: &Response) -> Value {
+        serde_json::from_str(&response.body).unwrap()
+    }
+
+    #[test]
+    fn endpoints_test() {
+        let response = handle(
+            "POST",
+            "/transliterate",
+            r#"{"text": "ғозал", "alphabet": "lat"}"#.as_bytes(),
+        );
+        assert_eq!(response.status, 200);
+        assert_eq!(json(&response)["result"], "g‘ozal");
+
+        let response = handle("POST", "/numbers", r#"{"text": "12 daraxt"}"#.as_bytes());
+        assert_eq!(json(&response)["result"], "o‘n ikki daraxt");
+
+        let response = handle(
+            "POST",
+            "/numbers",
+            r#"{"text": "12 daraxt", "alphabet": "cyr"}"#.as_bytes(),
+        );
+        assert_eq!(json(&response)["result"], "ўн икки daraxt");
+
+        let response = handle(
+            "POST",
+            "/numbers",
+            r#"{"text": "XXI asr", "roman": true}"#.as_bytes(),
+        );
+        assert_eq!(json(&response)["result"], "yigirma birinchi asr");
+
+        let response = handle("POST", "/syllables/", r#"{"text": "chiroyli"}"#.as_bytes());
+        assert_eq!(json(&response)["result"], "chi-roy-li");
+
+        let response = handle(
+            "post",
+            "/sort?x=1",
+            r#"{"text": "chiroyli estafeta"}"#.as_bytes(),
+        );
+        assert_eq!(json(&response)["result"], "estafeta chiroyli");
+
+        let response = handle("POST", "/sort", r#"{"text": "34 12"}"#.as_bytes());
+        assert_eq!(json(&response)["result"], "12 34");
+
+        let response = handle(
+            "POST",
+            "/correct",
+            r#"{"text": "2022-йил 12 yanvar"}"#.as_bytes(),
+        );
+        assert_eq!(json(&response)["result"], "2022 йил 12-yanvar");
+    }
+
+    #[test]
+    fn errors_test() {
+        let response = handle(
+            "POST",
+            "/transliterate",
+            r#"{"text": "salom", "alphabet": "cyrl"}"#.as_bytes(),
+        );
+        assert_eq!(response.status, 422);
+        assert_eq!(json(&response)["error"], "InvalidAlphabet");
+        assert_eq!(json(&response)["details"]["InvalidAlphabet"], "cyrl");
+
+        let response = handle(
+            "POST",
+            "/sort",
+            r#"{"text": "12 34", "strict": true}"#.as_bytes(),
+        );
+        assert_eq!(response.status, 422);
+        assert_eq!(json(&response)["error"], "InvalidChar");
+
+        let response = handle("POST", "/numbers", b"not json");
+        assert_eq!(response.status, 400);
+        assert_eq!(json(&response)["error"], "BadRequest");
+
+        let response = handle("GET", "/numbers", b"");
+        assert_eq!(response.status, 405);
+
+        let response = handle("POST", "/unknown", b"{}");
+        assert_eq!(response.status, 404);
+        assert_eq!(json(&response)["error"], "NotFound");
+
+        // a response that can't be serialized is not the fault of the request
+        let unserializable = std::collections::HashMap::from([((1, 2), 3)]);
+        let response = to_json(&unserializable).unwrap_err().into_response();
+        assert_eq!(response.status, 500);
+        assert_eq!(json(&response)["error"], "Internal");
+    }
+}
@@ -1,12 +1,14 @@
 //! Functions to remove duplicated words from text.
 //!
 //! Both latin and cyrillic modes are supported.
-use pcre::Pcre;
-use itertools::Itertools;
+use crate::uzbek::tokenize::{self, TokenKind};
+use std::collections::HashSet;
 
 /// Removes word duplicates from a text.
 ///
-/// Given a String returns a new String without word duplications.
+/// Given a String returns a new String without word duplications:
+/// repeated words and numbers are dropped along with the whitespace
+/// before them, punctuation and everything else is kept as it is.
 ///
 /// # Example
 /// ```rust
@@ -17,18 +19,30 @@ use itertools::Itertools;
 /// assert_eq!(output, expected);
 /// ```
 pub fn remove(text: &str) -> String {
-    let mut input = text.to_string();
+    let tokens = tokenize::words(text);
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut result = String::with_capacity(text.len());
+    // whitespace is held back until it's known whether the word after it stays
+    let mut space = "";
 
-    let mut re = Pcre::compile(r"[\p{Cyrillic}|\p{Latin}|0-9|\-_]+").unwrap();
-    let matches = re.matches(&input);
-
-    if matches.count() > 0 {
-        let mut text: Vec<&str> = input.split_whitespace().collect();
-        text = text.into_iter().unique().collect();
-        input = text.join(" ");
+    for token in &tokens {
+        match token.kind {
+            TokenKind::Whitespace => {
+                result.push_str(space);
+                space = &token.text;
+                continue;
+            }
+            TokenKind::Word | TokenKind::Number if !seen.insert(&token.text) => {}
+            _ => {
+                result.push_str(space);
+                result.push_str(&token.text);
+            }
+        }
+        space = "";
     }
+    result.push_str(space);
 
-    input
+    result
 }
 
 #[cfg(test)]
@@ -38,5 +52,15 @@ mod as_tests {
     #[test]
     fn remove_duplicates_test() {
         assert_eq!(remove("salom salom xato salom"), String::from("salom xato"));
+        assert_eq!(
+            remove("olma, nok, olma. Nok"),
+            String::from("olma, nok,. Nok")
+        );
+        assert_eq!(
+            remove("Salom, dunyo! Salom."),
+            String::from("Salom, dunyo!.")
+        );
+        assert_eq!(remove("a - b"), String::from("a - b"));
+        assert_eq!(remove(" 5 va 5 \n"), String::from(" 5 va \n"));
     }
-}
\ No newline at end of file
+}
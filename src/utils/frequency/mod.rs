@@ -2,13 +2,15 @@
 //!
 //! Both latin and cyrillic modes are supported.
 use std::collections::HashMap;
-use pcre::Pcre;
 use itertools::Itertools;
+use crate::uzbek::tokenize::{self, TokenKind};
 
 /// Provides a list of words and their number of occurences in a given text.
 ///
 /// Given a String returns a HashMap with words as keys
 /// and number of their occurrences in the String as values.
+/// Numbers, URLs, emails and usernames are counted as words,
+/// punctuation around the words is ignored.
 ///
 /// # Example
 ///```rust
@@ -24,22 +26,11 @@ use itertools::Itertools;
 /// assert_eq!(output, expected);
 /// ```
 pub fn count(text: &str) -> HashMap<String, usize> {
-    let mut result: HashMap<String, usize> = HashMap::new();
-    let mut pre_result: HashMap<&str, usize> = HashMap::new();
-
-    let mut re = Pcre::compile(r"[\p{Cyrillic}|\p{Latin}|0-9|\-_]+").unwrap();
-    let matches = re.matches(text);
-
-    if matches.count() > 0 {
-        let input_vec = text.split_whitespace();
-        pre_result = input_vec.into_iter().counts();
-    }
-
-    for (word, count) in pre_result {
-        result.insert(String::from(word), count);
-    }
-
-    result
+    tokenize::words(text)
+        .into_iter()
+        .filter(|token| !matches!(token.kind, TokenKind::Whitespace | TokenKind::Punctuation))
+        .map(|token| token.text)
+        .counts()
 }
 
 #[cfg(test)]
@@ -50,5 +41,8 @@ mod as_tests {
     fn word_frequency_test() {
         assert_eq!(count("salom xato quyosh salom mushuk"),
                    HashMap::from([(String::from("salom"), 2), (String::from("xato"), 1), (String::from("quyosh"), 1), (String::from("mushuk"), 1)]));
+        assert_eq!(count("Salom, salom! O‘zbekiston o‘zbekiston-ku."),
+                   HashMap::from([(String::from("Salom"), 1), (String::from("salom"), 1), (String::from("O‘zbekiston"), 1), (String::from("o‘zbekiston-ku"), 1)]));
+        assert_eq!(count("... !"), HashMap::new());
     }
 }
\ No newline at end of file
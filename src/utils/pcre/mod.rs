@@ -31,14 +31,23 @@ pub fn wrap_regex(text: &str, pattern: &str) -> String {
 /// Wraps all regex matches of pcre crate
 /// in 〈〉 brackets in order to preserve some
 /// text from some operations in korrektor.
+///
+/// Only the matched ranges are wrapped, the same text
+/// elsewhere (or inside another word) is left as it is.
 pub fn wrap_matches(text: &str, matches: MatchIterator) -> String{
-    let mut result = text.to_string();
+    let mut result = String::with_capacity(text.len());
+    let mut last = 0;
 
     for m in matches {
-        let captured = String::from(m.group(0));
-        let replacement = String::from("〈") + &*captured + "〉";
-        result = result.replace(&captured, &replacement);
+        let (start, end) = (m.group_start(0), m.group_end(0));
+
+        result.push_str(&text[last..start]);
+        result.push('〈');
+        result.push_str(&text[start..end]);
+        result.push('〉');
+        last = end;
     }
+    result.push_str(&text[last..]);
 
     result
 }
@@ -76,6 +85,9 @@ mod as_tests {
         let expected = "@ki-d 〈https://nyan.com〉 go'zal @crystalny";
 
         assert_eq!(wrap_regex(input, pattern), expected.to_string());
+
+        // the same text outside of the match is not wrapped
+        assert_eq!(wrap_regex("1 12 21", "(?<![0-9])1(?![0-9])"), "〈1〉 12 21");
     }
 
     #[test]
@@ -1,6 +1,14 @@
 use crate::utils::pcre as ipcre;
 use pcre::Pcre;
 
+pub(crate) const EMAIL_PATTERN: &str =
+    "([\\w-]+(?:\\.[\\w-]+)*)@((?:[\\w-]+\\.)*\\w[\\w-]{0,66})\\.([a-z]{2,6}(?:\\.[a-z]{2})?)";
+
+pub(crate) const URL_PATTERN: &str = "(?i)\\b(?:(?:https?|ftp|file|ssh):\\/\\/|www\\.|ftp\\.)[-A-Z0-9+&@#\\/%=~_|$?!:,.]*[A-Z0-9+&@#\\/%=~_|$]";
+
+pub(crate) const USERNAME_PATTERN: &str =
+    r"@(?!.*\-|.*\-$)[a-zA-Z0-9][\w-]+[a-zA-Z0-9]{0,39}";
+
 // wrapping special text with 〈〉 to avoid correction and transliteration
 pub fn get_wrapped_text(text: &str) -> String {
     let mut result = wrap_mails(text);
@@ -11,24 +19,21 @@ pub fn get_wrapped_text(text: &str) -> String {
 }
 
 fn wrap_mails(text: &str) -> String {
-    let mut re: Pcre = Pcre::compile(
-        "([\\w-]+(?:\\.[\\w-]+)*)@((?:[\\w-]+\\.)*\\w[\\w-]{0,66})\\.([a-z]{2,6}(?:\\.[a-z]{2})?)",
-    )
-    .unwrap();
+    let mut re: Pcre = Pcre::compile(EMAIL_PATTERN).unwrap();
     let matches = re.matches(text);
 
     ipcre::wrap_matches(text, matches)
 }
 
 fn wrap_urls(text: &str) -> String {
-    let mut re: Pcre = Pcre::compile("(?i)\\b(?:(?:https?|ftp|file|ssh):\\/\\/|www\\.|ftp\\.)[-A-Z0-9+&@#\\/%=~_|$?!:,.]*[A-Z0-9+&@#\\/%=~_|$]").unwrap();
+    let mut re: Pcre = Pcre::compile(URL_PATTERN).unwrap();
     let matches = re.matches(text);
 
     ipcre::wrap_matches(text, matches)
 }
 
 fn wrap_usernames(text: &str) -> String {
-    let mut re: Pcre = Pcre::compile(r"([\w-]+(?:\.[\w-]+)*)@((?:[\w-]+\.)*\w[\w-]{0,66})\.([a-z]{2,6}(?:\.[a-z]{2})?)|@(?!.*\-|.*\-$)[a-zA-Z0-9][\w-]+[a-zA-Z0-9]{0,39}").unwrap();
+    let mut re: Pcre = Pcre::compile(&format!("{EMAIL_PATTERN}|{USERNAME_PATTERN}")).unwrap();
     let matches = re.matches(text);
 
     ipcre::wrap_matches(text, matches)
@@ -0,0 +1,261 @@
+//! Alphabets used to write Uzbek language.
+//!
+//! Both latin and cyrillic alphabets are in use, so most
+//! of the functions in this crate expect to know which one
+//! the text is written in (or should be written in).
+use crate::error::KorrektorError;
+use crate::uzbek::alphabetic::prelude::CHAR_ORDER;
+use crate::uzbek::transliterator::prelude::{CYRILLIC_C2L, CYRILLIC_L2C, LATIN_C2L, LATIN_L2C};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::str::FromStr;
+
+static LATIN_LETTERS: Lazy<HashSet<char>> = Lazy::new(|| {
+    let inventory = LATIN_L2C.iter().chain(LATIN_C2L).chain(CHAR_ORDER);
+
+    // CHAR_ORDER also holds sorting placeholders (ŏ, ğ, š, č, ...) which never occur in text
+    inventory
+        .flat_map(|letters| letters.chars())
+        .filter(char::is_ascii_alphabetic)
+        .collect()
+});
+
+static CYRILLIC_LETTERS: Lazy<HashSet<char>> = Lazy::new(|| {
+    let inventory = CYRILLIC_C2L.iter().chain(CYRILLIC_L2C).chain(CHAR_ORDER);
+
+    inventory
+        .flat_map(|letters| letters.chars())
+        .filter(|letter| ('\u{0400}'..='\u{04FF}').contains(letter))
+        .collect()
+});
+
+pub(crate) const APOSTROPHES: &[char] = &['ʻ', 'ʼ', '\'', '‘', '’', '‛', '′', 'ʽ', '`'];
+
+/// Alphabet of Uzbek text.
+///
+/// Parsed from and displayed as `"lat"` and `"cyr"`,
+/// the same short names are used when (de)serializing.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::Alphabet;
+///
+/// let alphabet: Alphabet = "cyr".parse().unwrap();
+/// assert_eq!(alphabet, Alphabet::Cyrillic);
+/// assert_eq!(alphabet.to_string(), "cyr");
+/// assert!("cyrl".parse::<Alphabet>().is_err());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Alphabet {
+    #[default]
+    #[serde(rename = "lat")]
+    Latin,
+    #[serde(rename = "cyr")]
+    Cyrillic,
+}
+
+impl Alphabet {
+    /// Short name of the alphabet, either `"lat"` or `"cyr"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Alphabet::Latin => "lat",
+            Alphabet::Cyrillic => "cyr",
+        }
+    }
+}
+
+impl FromStr for Alphabet {
+    type Err = KorrektorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lat" => Ok(Alphabet::Latin),
+            "cyr" => Ok(Alphabet::Cyrillic),
+            _ => Err(KorrektorError::InvalidAlphabet(s.to_string())),
+        }
+    }
+}
+
+impl Display for Alphabet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Script statistics of a text, returned by [`detect_script`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptReport {
+    /// Share of latin Uzbek letters among all letters of the text.
+    pub latin: f64,
+    /// Share of cyrillic Uzbek letters among all letters of the text.
+    pub cyrillic: f64,
+    /// Share of letters that belong to neither of the Uzbek alphabets.
+    pub other: f64,
+    /// Alphabet most of the Uzbek letters are written in,
+    /// `None` if the text has no Uzbek letters at all.
+    pub dominant: Option<Alphabet>,
+    /// Words that contain both latin and cyrillic letters.
+    pub mixed: Vec<MixedWord>,
+}
+
+/// Word written with letters of both alphabets.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MixedWord {
+    pub word: String,
+    /// Byte range of the word in the original text.
+    pub span: Range<usize>,
+}
+
+/// Detects which alphabet the given text is written in.
+///
+/// Letters are classified using the same letter inventories the
+/// transliterator and the sorting functions use. Apostrophes (as in `o‘`)
+/// are kept as a part of a word but not counted as letters.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::{detect_script, Alphabet};
+///
+/// // latin "o" in the cyrillic word "салом"
+/// let report = detect_script("салoм, дўстим!");
+///
+/// assert_eq!(report.dominant, Some(Alphabet::Cyrillic));
+/// assert_eq!(report.mixed.len(), 1);
+/// assert_eq!(report.mixed[0].word, "салoм");
+/// assert_eq!(report.mixed[0].span, 0..9);
+/// ```
+pub fn detect_script(text: &str) -> ScriptReport {
+    let mut latin = 0;
+    let mut cyrillic = 0;
+    let mut other = 0;
+    let mut mixed: Vec<MixedWord> = vec![];
+
+    // (start of the word, has latin letters, has cyrillic letters)
+    let mut word: Option<(usize, bool, bool)> = None;
+
+    for (index, char) in text.char_indices().chain([(text.len(), ' ')]) {
+        let is_latin = LATIN_LETTERS.contains(&char);
+        let is_cyrillic = CYRILLIC_LETTERS.contains(&char);
+
+        if is_latin {
+            latin += 1;
+        } else if is_cyrillic {
+            cyrillic += 1;
+        } else if char.is_alphabetic() {
+            other += 1;
+        }
+
+        if char.is_alphabetic() || APOSTROPHES.contains(&char) {
+            let (start, has_latin, has_cyrillic) = word.unwrap_or((index, false, false));
+            word = Some((start, has_latin || is_latin, has_cyrillic || is_cyrillic));
+        } else if let Some((start, has_latin, has_cyrillic)) = word.take() {
+            if has_latin && has_cyrillic {
+                mixed.push(MixedWord {
+                    word: text[start..index].to_string(),
+                    span: start..index,
+                });
+            }
+        }
+    }
+
+    let total = (latin + cyrillic + other) as f64;
+    let ratio = |count: usize| {
+        if total > 0.0 {
+            count as f64 / total
+        } else {
+            0.0
+        }
+    };
+
+    let dominant = match (latin, cyrillic) {
+        (0, 0) => None,
+        (latin, cyrillic) if cyrillic > latin => Some(Alphabet::Cyrillic),
+        _ => Some(Alphabet::Latin),
+    };
+
+    ScriptReport {
+        latin: ratio(latin),
+        cyrillic: ratio(cyrillic),
+        other: ratio(other),
+        dominant,
+        mixed,
+    }
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!("lat".parse::<Alphabet>().unwrap(), Alphabet::Latin);
+        assert_eq!("cyr".parse::<Alphabet>().unwrap(), Alphabet::Cyrillic);
+
+        for invalid in ["", "cyrl", "Cyr", "LAT", "latin"] {
+            match invalid.parse::<Alphabet>() {
+                Err(KorrektorError::InvalidAlphabet(value)) => assert_eq!(value, invalid),
+                other => panic!("expected an error for {invalid:?}, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!(Alphabet::Latin.to_string(), "lat");
+        assert_eq!(Alphabet::Cyrillic.to_string(), "cyr");
+    }
+
+    #[test]
+    fn serde_test() {
+        let pairs = [
+            (Alphabet::Latin, "\"lat\""),
+            (Alphabet::Cyrillic, "\"cyr\""),
+        ];
+
+        for (alphabet, json) in pairs {
+            assert_eq!(serde_json::to_string(&alphabet).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Alphabet>(json).unwrap(), alphabet);
+        }
+        assert!(serde_json::from_str::<Alphabet>("\"cyrl\"").is_err());
+    }
+
+    #[test]
+    fn detect_script_test() {
+        let report = detect_script("G‘ozal o‘zbek tili");
+        assert_eq!(report.dominant, Some(Alphabet::Latin));
+        assert_eq!(report.latin, 1.0);
+        assert_eq!(report.cyrillic, 0.0);
+        assert!(report.mixed.is_empty());
+
+        let report = detect_script("Ғозал ўзбек тили");
+        assert_eq!(report.dominant, Some(Alphabet::Cyrillic));
+        assert_eq!(report.cyrillic, 1.0);
+
+        // "щ" and "ы" are not Uzbek letters
+        let report = detect_script("щы ab");
+        assert_eq!(report.dominant, Some(Alphabet::Latin));
+        assert_eq!(report.other, 0.5);
+        assert_eq!(report.latin, 0.5);
+
+        let report = detect_script("12, 34!");
+        assert_eq!(report.dominant, None);
+        assert_eq!(report.latin, 0.0);
+        assert_eq!(report.other, 0.0);
+    }
+
+    #[test]
+    fn detect_mixed_words_test() {
+        let text = "Tоshkent shahri va o‘zbеk tili";
+        let report = detect_script(text);
+
+        let words: Vec<&str> = report.mixed.iter().map(|m| m.word.as_str()).collect();
+        assert_eq!(words, vec!["Tоshkent", "o‘zbеk"]);
+
+        for mixed in report.mixed {
+            assert_eq!(&text[mixed.span], mixed.word);
+        }
+    }
+}
@@ -0,0 +1,387 @@
+//! Comparison of Uzbek words in alphabetical order.
+use super::{prelude, to_sortable};
+use crate::uzbek::transliterator;
+use once_cell::sync::Lazy;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+// first weights of numbers, letters of the alphabet and other letters, every weight is above zero
+const NUMBER: u32 = 1;
+const LETTER: u32 = 0x100;
+const OTHER: u32 = 0x1_0000;
+
+// place of a letter of the alphabet in the table and among its lowercase letters
+#[derive(Debug, Clone, Copy)]
+struct Letter {
+    position: u32,
+    folded: u32,
+    upper: bool,
+}
+
+impl Letter {
+    fn weight(&self, case_first: CaseFirst) -> u32 {
+        match case_first {
+            CaseFirst::Off => LETTER + self.position,
+            CaseFirst::Lower | CaseFirst::Upper => LETTER + self.folded,
+        }
+    }
+}
+
+static LETTERS: Lazy<HashMap<char, Letter>> = Lazy::new(|| {
+    let letters: Vec<char> = prelude::CHAR_ORDER
+        .iter()
+        .flat_map(|letter| letter.chars())
+        .collect();
+    let lowercase: Vec<char> = letters
+        .iter()
+        .copied()
+        .filter(|letter| !letter.is_uppercase())
+        .collect();
+
+    let mut weights = HashMap::new();
+    for (position, &char) in letters.iter().enumerate() {
+        let lower = char.to_lowercase().next().unwrap_or(char);
+
+        if let Some(folded) = lowercase.iter().position(|&letter| letter == lower) {
+            weights.entry(char).or_insert(Letter {
+                position: position as u32,
+                folded: folded as u32,
+                upper: char != lower,
+            });
+        }
+    }
+    // placeholders of SH and CH
+    weights.insert(prelude::SH, weights[&'Š']);
+    weights.insert(prelude::CH, weights[&'Č']);
+
+    weights
+});
+
+/// Compares Uzbek words in the order of [`sort`](super::sort).
+///
+/// Words are compared level by level, a level only matters if the words are equal at
+/// the ones before it:
+/// 1. letters in the order of the alphabet, where every lowercase letter of a script
+///    goes before every uppercase one (`zafar` before `Anor`) unless
+///    [`case_first`](UzbekCollator::case_first) ignores case. Digraphs (sh, ch)
+///    and letters with an apostrophe (o‘, g‘) are single letters with their own place.
+///    Numbers go before letters and are compared by their value (`2` before `10`),
+///    letters that are not in the alphabet (`c`, `w`, `ы`, `щ`) go after it whatever
+///    their case. Punctuation, apostrophes and other symbols are ignored;
+/// 2. case, lowercase letters first unless `case_first` is [`CaseFirst::Upper`];
+/// 3. diacritics of the letters that are not in the alphabet (`e` before `é`);
+/// 4. all the characters as they are, so only the same words are equal.
+///
+/// With [`interleave_scripts`](UzbekCollator::interleave_scripts) cyrillic words are
+/// compared by their latin spelling at the first three levels, so `олма` goes next to
+/// `olma` instead of after every latin word.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::alphabetic::{CaseFirst, UzbekCollator};
+///
+/// struct Record {
+///     name: &'static str,
+/// }
+///
+/// let collator = UzbekCollator::new();
+/// let mut records = vec![
+///     Record { name: "chiroyli" },
+///     Record { name: "o‘zbek" },
+///     Record { name: "estafeta" },
+///     Record { name: "zafar" },
+/// ];
+/// records.sort_by(|a, b| collator.compare(a.name, b.name));
+///
+/// let names: Vec<&str> = records.iter().map(|record| record.name).collect();
+/// assert_eq!(names, ["estafeta", "zafar", "o‘zbek", "chiroyli"]);
+///
+/// let collator = UzbekCollator {
+///     case_first: CaseFirst::Upper,
+///     interleave_scripts: true,
+/// };
+/// let mut names = vec!["олма", "nok", "Olma", "olma"];
+/// names.sort_by(|a, b| collator.compare(a, b));
+/// assert_eq!(names, ["nok", "Olma", "olma", "олма"]);
+///
+/// let mut names = vec!["олма", "nok", "Olma", "olma"];
+/// names.sort_by(|a, b| UzbekCollator::new().compare(a, b));
+/// assert_eq!(names, ["nok", "olma", "Olma", "олма"]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UzbekCollator {
+    /// Whether case is ignored and which of the words that differ only in case
+    /// goes first, [`CaseFirst::Off`] by default.
+    pub case_first: CaseFirst,
+    /// Compare cyrillic words by their latin spelling, `false` by default.
+    pub interleave_scripts: bool,
+}
+
+/// Order of lowercase and uppercase letters, see [`UzbekCollator`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CaseFirst {
+    /// Case is not ignored, lowercase letters of the alphabet go before uppercase
+    /// ones the way they do in its table: `zafar` before `Anor` before `Zafar`.
+    #[default]
+    Off,
+    /// Case is ignored, then lowercase first: `Anor` before `zafar` before `Zafar`.
+    Lower,
+    /// Case is ignored, then uppercase first: `Anor` before `Zafar` before `zafar`.
+    Upper,
+}
+
+impl UzbekCollator {
+    pub fn new() -> Self {
+        UzbekCollator::default()
+    }
+
+    /// Compares two words, the comparator for [`slice::sort_by`].
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        self.collation_key(a).cmp(&self.collation_key(b))
+    }
+
+    /// Returns a key of the word, the keys of two words compare
+    /// byte by byte the same as [`compare`](UzbekCollator::compare) compares the words.
+    pub fn collation_key(&self, text: &str) -> Vec<u8> {
+        self.sortable_key(&to_sortable(text.to_string()))
+    }
+
+    // key of a word with sortable letters: weights of every level in three bytes
+    // each, levels are separated with zeros so a shorter level goes first
+    pub(super) fn sortable_key(&self, word: &str) -> Vec<u8> {
+        let latin;
+        let weighted = if self.interleave_scripts && word.chars().any(is_cyrillic) {
+            latin = to_sortable(transliterator::to_latin(word.to_string()));
+            &latin
+        } else {
+            word
+        };
+
+        let [primary, case, diacritic] = levels(weighted, self.case_first);
+        // the word as it is, so an interleaved word is not equal to its spelling
+        let chars = word.chars().map(|char| char as u32 + 1).collect();
+
+        let mut key = vec![];
+        for (index, level) in [primary, case, diacritic, chars].iter().enumerate() {
+            if index > 0 {
+                key.extend([0, 0, 0]);
+            }
+            for weight in level {
+                key.extend(&weight.to_be_bytes()[1..]);
+            }
+        }
+
+        key
+    }
+}
+
+/// Returns a key of the word to be stored in a database index.
+///
+/// Keys compare byte by byte in the alphabetical order of the words,
+/// see [`UzbekCollator`].
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::alphabetic;
+///
+/// assert!(alphabetic::collation_key("o‘zbek") > alphabetic::collation_key("zafar"));
+/// assert_eq!(alphabetic::collation_key("o'zbek"), alphabetic::collation_key("o‘zbek"));
+/// ```
+pub fn collation_key(text: &str) -> Vec<u8> {
+    UzbekCollator::new().collation_key(text)
+}
+
+fn is_cyrillic(char: char) -> bool {
+    ('\u{0400}'..='\u{04FF}').contains(&char)
+}
+
+// weight of the case of a letter, the case that goes first weighs less
+fn case_weight(upper: bool, case_first: CaseFirst) -> u32 {
+    1 + (upper != (case_first == CaseFirst::Upper)) as u32
+}
+
+// weights of the letters and numbers of a word at the first three levels
+fn levels(word: &str, case_first: CaseFirst) -> [Vec<u32>; 3] {
+    let mut primary = vec![];
+    let mut case = vec![];
+    let mut diacritic = vec![];
+
+    let mut chars = word.chars().peekable();
+    while let Some(char) = chars.next() {
+        if char.is_ascii_digit() {
+            let mut digits = String::from(char);
+            while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                digits.push(digit);
+            }
+
+            // the number of digits first, then the digits themselves
+            let digits = digits.trim_start_matches('0');
+            primary.push(NUMBER);
+            primary.push(digits.len() as u32 + 1);
+            primary.extend(digits.bytes().map(|digit| (digit - b'0') as u32 + 1));
+            case.push(1);
+            diacritic.push(1);
+        } else if let Some(letter) = LETTERS.get(&char) {
+            primary.push(letter.weight(case_first));
+            case.push(case_weight(letter.upper, case_first));
+            diacritic.push(1);
+        } else if char.is_alphabetic() {
+            let lower = char.to_lowercase().next().unwrap_or(char);
+            let (base, mark) = match prelude::DIACRITICS
+                .iter()
+                .find(|(letter, _)| *letter == lower)
+            {
+                Some(&(_, base)) => (base, 2 + lower as u32),
+                None => (lower, 1),
+            };
+
+            // a letter of the alphabet with a diacritic is in the place of its case
+            let cased = match char != lower {
+                true => base.to_uppercase().next().unwrap_or(base),
+                false => base,
+            };
+            primary.push(match LETTERS.get(&cased) {
+                Some(letter) => letter.weight(case_first),
+                None => OTHER + base as u32,
+            });
+            case.push(case_weight(char != lower, case_first));
+            diacritic.push(mark);
+        }
+    }
+
+    [primary, case, diacritic]
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    #[test]
+    fn compare_test() {
+        let collator = UzbekCollator::new();
+
+        assert_eq!(collator.compare("chiroyli", "chilonzor"), Ordering::Greater);
+        assert_eq!(collator.compare("shahar", "sabzi"), Ordering::Greater);
+        assert_eq!(collator.compare("o'zbek", "o‘zbek"), Ordering::Equal);
+        assert_eq!(collator.compare("g‘oz", "gul"), Ordering::Greater);
+        assert_eq!(collator.compare("bir", "birinchi"), Ordering::Less);
+        assert_eq!(collator.compare("чиройли", "ўзбек"), Ordering::Less);
+        assert_eq!(collator.compare("вагон", "гул"), Ordering::Less);
+        // not in the alphabet
+        assert_eq!(collator.compare("zafar", "çay"), Ordering::Less);
+        assert_eq!(collator.compare("ñandu", "nok"), Ordering::Less);
+        assert_eq!(collator.compare("ҳаёт", "щи"), Ordering::Less);
+        // lowercase letters before uppercase ones
+        assert_eq!(collator.compare("zafar", "Anor"), Ordering::Less);
+        assert_eq!(collator.compare("Zafar", "ёз"), Ordering::Less);
+        // SH and CH are letters, Ö and Ü are not
+        assert_eq!(collator.compare("SHAHAR", "Sabzi"), Ordering::Greater);
+        assert_eq!(collator.compare("Über", "Uber"), Ordering::Greater);
+        assert_eq!(collator.compare("Über", "Uzum"), Ordering::Less);
+        assert_eq!(collator.compare("Über", "CHIROYLI"), Ordering::Less);
+    }
+
+    #[test]
+    fn levels_test() {
+        fn sorted<'a>(words: &[&'a str]) -> Vec<&'a str> {
+            sorted_with(words, CaseFirst::Lower)
+        }
+        fn sorted_with<'a>(words: &[&'a str], case_first: CaseFirst) -> Vec<&'a str> {
+            let collator = UzbekCollator {
+                case_first,
+                ..UzbekCollator::new()
+            };
+            let mut sorted = words.to_vec();
+            sorted.sort_by(|a, b| collator.compare(a, b));
+            sorted
+        }
+
+        // case as a part of a letter by default
+        assert_eq!(
+            sorted_with(&["Zafar", "o‘rik", "zafar", "Anor", "ёз"], CaseFirst::Off),
+            ["zafar", "o‘rik", "Anor", "Zafar", "ёз"]
+        );
+        assert_eq!(
+            sorted_with(&["Ékran", "ekran", "Ekran"], CaseFirst::Off),
+            ["ekran", "Ekran", "Ékran"]
+        );
+        // case after letters
+        assert_eq!(
+            sorted(&["Zafar", "o‘rik", "zafar", "Anor"]),
+            ["Anor", "zafar", "Zafar", "o‘rik"]
+        );
+        assert_eq!(
+            sorted(&["SHAHAR", "Shahar", "sabzi"]),
+            ["sabzi", "Shahar", "SHAHAR"]
+        );
+        // numbers by value before letters
+        assert_eq!(
+            sorted(&["a", "10-sinf", "2-sinf", "02"]),
+            ["02", "2-sinf", "10-sinf", "a"]
+        );
+        // punctuation ignored, then the word as it is
+        assert_eq!(
+            sorted(&["ma'no", "man", "mano", "a-b", "ab"]),
+            ["a-b", "ab", "man", "mano", "ma'no"]
+        );
+        // diacritics after case
+        assert_eq!(sorted(&["café", "cafe", "Cafe"]), ["cafe", "café", "Cafe"]);
+    }
+
+    #[test]
+    fn options_test() {
+        let upper = UzbekCollator {
+            case_first: CaseFirst::Upper,
+            ..UzbekCollator::new()
+        };
+        assert_eq!(upper.compare("Zafar", "zafar"), Ordering::Less);
+        assert_eq!(upper.compare("Zafar", "o‘rik"), Ordering::Less);
+        assert_eq!(upper.compare("SHAHAR", "Shahar"), Ordering::Less);
+        assert_eq!(upper.compare("Café", "cafe"), Ordering::Less);
+
+        let interleaved = UzbekCollator {
+            interleave_scripts: true,
+            ..UzbekCollator::new()
+        };
+        assert_eq!(interleaved.compare("олма", "nok"), Ordering::Greater);
+        assert_eq!(interleaved.compare("олма", "o‘rik"), Ordering::Less);
+        assert_eq!(interleaved.compare("шаҳар", "sabzi"), Ordering::Greater);
+        assert_eq!(interleaved.compare("Шаҳар", "shahar"), Ordering::Greater);
+        // the same spelling is not the same word
+        assert_eq!(interleaved.compare("olma", "олма"), Ordering::Less);
+        assert_eq!(
+            UzbekCollator::new().compare("олма", "o‘rik"),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn collation_key_test() {
+        let words = [
+            "chiroyli",
+            "sabzi",
+            "Shahar",
+            "shahar",
+            "ma'no",
+            "mana",
+            "ўзбек",
+            "ы",
+            "",
+            "12",
+            "9",
+            "é",
+            "E",
+        ];
+        let collator = UzbekCollator::new();
+
+        for a in words {
+            for b in words {
+                assert_eq!(
+                    collation_key(a).cmp(&collation_key(b)),
+                    collator.compare(a, b),
+                    "{a} {b}"
+                );
+            }
+        }
+    }
+}
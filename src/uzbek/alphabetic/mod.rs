@@ -3,12 +3,95 @@
 //! Both cyrillic and latin modes can be used.
 use crate::error::KorrektorError;
 use crate::utils::pcre;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
 
-mod prelude;
+mod collator;
+pub(crate) mod prelude;
+
+pub use collator::{collation_key, CaseFirst, UzbekCollator};
+
+// positions of the letters in CHAR_ORDER, the first one of a repeated letter
+static CHAR_VALUES: Lazy<HashMap<char, usize>> = Lazy::new(|| {
+    let mut values = HashMap::new();
+
+    for (position, letter) in prelude::CHAR_ORDER.iter().enumerate() {
+        for char in letter.chars() {
+            values.entry(char).or_insert(position);
+        }
+    }
+
+    values
+});
+
+// TO_SORT compiled once, the collator converts every word it compares
+static TO_SORT: Lazy<Vec<(Regex, &str)>> = Lazy::new(|| {
+    prelude::TO_SORT
+        .iter()
+        .map(|(pattern, replacement)| (Regex::new(pattern).unwrap(), *replacement))
+        .collect()
+});
+
+/// Settings of [`sort_with`].
+#[derive(Debug, Clone, Default)]
+pub struct SortOptions {
+    /// Accept only the letters of the alphabet and compare words letter by letter
+    /// by their place in the alphabet table, `false` by default. Words are sorted
+    /// the way they used to be, except that the cyrillic table now has `в`,
+    /// which was rejected, and lowercase `ю` before `я` as in uppercase.
+    pub strict: bool,
+    /// Whether case is ignored and which of the words that differ only in case goes
+    /// first, [`CaseFirst::Off`] by default. Ignored in strict mode.
+    pub case_first: CaseFirst,
+    /// Sort cyrillic words among latin ones by their latin spelling, `false` by default.
+    /// Ignored in strict mode.
+    pub interleave_scripts: bool,
+    /// Sort in descending order, `false` by default.
+    pub descending: bool,
+    /// Keep only the first of the same words, `o'zbek` and `o‘zbek` are the same,
+    /// `false` by default.
+    pub unique: bool,
+    /// What the sorted words are joined with, a space by default.
+    pub separator: Separator,
+}
+
+/// What the sorted words are joined with, see [`SortOptions`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Separator {
+    /// `olma nok`
+    #[default]
+    Space,
+    /// `olma\nnok`, one word per line.
+    Newline,
+}
+
+impl Separator {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Separator::Space => " ",
+            Separator::Newline => "\n",
+        }
+    }
+}
+
+impl SortOptions {
+    fn collator(&self) -> UzbekCollator {
+        UzbekCollator {
+            case_first: self.case_first,
+            interleave_scripts: self.interleave_scripts,
+        }
+    }
+}
 
 /// Sorts words in alphabetically ascending order.
 ///
-/// Given String of text returns a new String with words sorted and separated with a newline.
+/// Given String of text returns a new String with words sorted and separated with a space,
+/// [`sort_with`] can separate them with a newline instead.
+/// Words are compared the way [`UzbekCollator`] does: by letters in the order of the
+/// alphabet, lowercase letters of a script before uppercase ones. Numbers go before
+/// letters and are sorted by their value, letters that are not in the alphabet go
+/// after it, punctuation is ignored.
 ///
 /// # Example
 /// ```rust
@@ -17,12 +100,60 @@ mod prelude;
 /// let output = alphabetic::sort("G‘ozal estafeta chilonzor o'zbek chiroyli");
 /// let expected = "estafeta o‘zbek chilonzor chiroyli G‘ozal".to_string();
 /// assert_eq!(output.unwrap(), expected);
+///
+/// let output = alphabetic::sort("10-uy, 2-uy, Щука ёз");
+/// let expected = "2-uy, 10-uy, ёз Щука".to_string();
+/// assert_eq!(output.unwrap(), expected);
 ///```
 pub fn sort(text: &str) -> Result<String, KorrektorError> {
+    sort_with(text, &SortOptions::default())
+}
+
+/// Sorts words in alphabetically ascending order with the given options.
+///
+/// See [`sort`] and [`SortOptions`].
+///
+/// # Errors
+/// In strict mode returns [`KorrektorError::InvalidChar`] for the first character
+/// that is not a letter of the alphabet (a digit, punctuation or a foreign letter).
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::alphabetic::{self, CaseFirst, Separator, SortOptions};
+///
+/// let options = SortOptions {
+///     case_first: CaseFirst::Upper,
+///     interleave_scripts: true,
+///     ..SortOptions::default()
+/// };
+/// let output = alphabetic::sort_with("olma Nok олма nok", &options);
+/// assert_eq!(output.unwrap(), "Nok nok olma олма".to_string());
+///
+/// let options = SortOptions {
+///     descending: true,
+///     unique: true,
+///     separator: Separator::Newline,
+///     ..SortOptions::default()
+/// };
+/// let output = alphabetic::sort_with("o'zbek nok o‘zbek olma nok", &options);
+/// assert_eq!(output.unwrap(), "o‘zbek\nolma\nnok".to_string());
+///
+/// let options = SortOptions {
+///     strict: true,
+///     ..SortOptions::default()
+/// };
+///
+/// let output = alphabetic::sort_with("G‘ozal estafeta chilonzor o'zbek chiroyli", &options);
+/// let expected = "estafeta o‘zbek chilonzor chiroyli G‘ozal".to_string();
+/// assert_eq!(output.unwrap(), expected);
+///
+/// assert!(alphabetic::sort_with("12 34", &options).is_err());
+///```
+pub fn sort_with(text: &str, options: &SortOptions) -> Result<String, KorrektorError> {
     // replace complex symbols in text with sortable alternatives
     let sortable = &to_sortable(text.to_string());
 
-    let sorted_intermediate = sort_sortable(sortable)?;
+    let sorted_intermediate = sort_sortable(sortable, options)?;
 
     // replace sortable alternatives with original values after sorting
     Ok(from_sortable(sorted_intermediate))
@@ -31,7 +162,9 @@ pub fn sort(text: &str) -> Result<String, KorrektorError> {
 fn to_sortable(text: String) -> String {
     let mut input: String = text;
 
-    input = pcre::replace_pairs(&input, prelude::TO_SORT);
+    for (re, replacement) in TO_SORT.iter() {
+        input = re.replace_all(&input, *replacement).to_string();
+    }
 
     input
 }
@@ -44,67 +177,43 @@ fn from_sortable(text: String) -> String {
     input
 }
 
-fn usort(string1: &str, string2: &str) -> Result<i8, KorrektorError> {
-    let length = std::cmp::min(string1.len() - 1, string2.len() - 1);
-
-    for i in 0..length {
-        let char1 = match string1.chars().nth(i) {
-            Some(char) => char,
-            None => panic!("Error in usort: no char at the index {i} in &str: {string1}"),
-        };
-        let char2 = match string2.chars().nth(i) {
-            Some(char) => char,
-            None => panic!("Error in usort: no char at the index {i} in &str: {string2}"),
-        };
-
-        // get position of characters in the alphabet
-        let value1 = get_value(char1)?;
-        let value2 = get_value(char2)?;
-
-        match value1.cmp(&value2) {
-            std::cmp::Ordering::Less => return Ok(-1),
-            std::cmp::Ordering::Greater => return Ok(1),
-            std::cmp::Ordering::Equal => continue,
-        };
+// key of a sortable word, in strict mode positions of its letters in the alphabet
+fn sort_key(word: &str, options: &SortOptions) -> Result<Vec<u8>, KorrektorError> {
+    if !options.strict {
+        return Ok(options.collator().sortable_key(word));
     }
 
-    match (string1.len()).cmp(&string2.len()) {
-        std::cmp::Ordering::Less => Ok(-1),
-        std::cmp::Ordering::Greater => Ok(1),
-        std::cmp::Ordering::Equal => Ok(0),
-    }
+    // there are less than 256 letters
+    word.chars()
+        .map(|char| Ok(get_value(char)? as u8))
+        .collect()
 }
 
-fn sort_sortable(text: &str) -> Result<String, KorrektorError> {
-    let mut sortable: Vec<&str> = text.split_whitespace().collect();
-    let mut len = sortable.len();
-
-    let mut sorted = false;
-    while !sorted {
-        sorted = true;
-        for i in 0..len - 1 {
-            match usort(sortable[i], sortable[i + 1])? {
-                1 => {
-                    sortable.swap(i, i + 1);
-                    sorted = false;
-                }
-                _ => continue,
-            }
-        }
-        len -= 1;
+fn sort_sortable(text: &str, options: &SortOptions) -> Result<String, KorrektorError> {
+    // keys are computed once per word, equal words keep their order
+    let mut words = text
+        .split_whitespace()
+        .map(|word| Ok((sort_key(word, options)?, word)))
+        .collect::<Result<Vec<(Vec<u8>, &str)>, KorrektorError>>()?;
+    if options.descending {
+        words.sort_by(|a, b| b.0.cmp(&a.0));
+    } else {
+        words.sort_by(|a, b| a.0.cmp(&b.0));
     }
-
-    let mut result = String::new();
-    for word in sortable {
-        result.push_str(word);
-        result.push(' ');
+    // the same words are next to each other, apostrophes are the same in sortable text
+    if options.unique {
+        words.dedup_by(|a, b| a.1 == b.1);
     }
 
-    Ok(result.trim().to_string())
+    Ok(words
+        .iter()
+        .map(|(_, word)| *word)
+        .collect::<Vec<&str>>()
+        .join(options.separator.as_str()))
 }
 
 fn is_exceptioned(value: char) -> bool {
-    if value == 'Ö' || value == 'Ü' {
+    if value == prelude::SH || value == prelude::CH {
         return true;
     }
 
@@ -112,10 +221,10 @@ fn is_exceptioned(value: char) -> bool {
 }
 
 fn get_exceptioned_value(value: char) -> usize {
-    if value == 'Ö' {
+    if value == prelude::SH {
         return 55;
     }
-    if value == 'Ü' {
+    if value == prelude::CH {
         return 56;
     }
 
@@ -126,11 +235,8 @@ fn get_value(value: char) -> Result<usize, KorrektorError> {
     if is_exceptioned(value) {
         Ok(get_exceptioned_value(value))
     } else {
-        match prelude::CHAR_ORDER
-            .iter()
-            .position(|&r| r == value.to_string())
-        {
-            Some(num) => Ok(num),
+        match CHAR_VALUES.get(&value) {
+            Some(num) => Ok(*num),
             None => Err(KorrektorError::InvalidChar(value)),
         }
     }
@@ -143,45 +249,170 @@ mod as_tests {
     #[test]
     fn to_sortable_test() {
         let result = to_sortable("G'g' O'o' ShSHsh ChCHch ʻʼ'‘’‛′ʽ`".to_string());
-        assert_eq!(result, "Ğğ Ŏŏ ŠÖš ČÜč ʼʼʼʼʼʼʼʼʼ");
+        assert_eq!(result, "Ğğ Ŏŏ Š\u{E000}š Č\u{E001}č ʼʼʼʼʼʼʼʼʼ");
     }
 
     #[test]
     fn from_sortable_test() {
-        let result = from_sortable("Ğğ Ŏŏ ŠÖš ČÜč".to_string());
-        assert_eq!(result, "G‘g‘ O‘o‘ ShSHsh ChCHch");
+        let result = from_sortable("Ğğ Ŏŏ Š\u{E000}š Č\u{E001}č Öl Über".to_string());
+        assert_eq!(result, "G‘g‘ O‘o‘ ShSHsh ChCHch Öl Über");
     }
 
     #[test]
     fn is_exceptioned_test() {
-        assert!(is_exceptioned('Ö'));
-        assert!(is_exceptioned('Ü'));
+        assert!(is_exceptioned(prelude::SH));
+        assert!(is_exceptioned(prelude::CH));
+        assert!(!is_exceptioned('Ö'));
+        assert!(!is_exceptioned('Ü'));
     }
 
     #[test]
     fn get_exceptioned_value_test() {
-        assert_eq!(get_exceptioned_value('Ö'), 55);
-        assert_eq!(get_exceptioned_value('Ü'), 56);
+        assert_eq!(get_exceptioned_value(prelude::SH), 55);
+        assert_eq!(get_exceptioned_value(prelude::CH), 56);
+    }
+
+    #[test]
+    fn sort_key_test() {
+        let strict = SortOptions {
+            strict: true,
+            ..SortOptions::default()
+        };
+        let key = |word: &str| sort_key(word, &strict).unwrap();
+
+        assert!(key("čiroyli") > key("čilonzor"));
+        assert_eq!(key("čiroyli"), key("čiroyli"));
+        assert!(key("čilonzor") < key("čiroyli"));
+        // the last letter and multi-byte letters count too
+        assert!(key("ab") < key("ad"));
+        assert!(key("ўзбек") < key("ўзбекча"));
+        assert!(matches!(
+            sort_key("a,b", &strict),
+            Err(KorrektorError::InvalidChar(','))
+        ));
+        assert_eq!(
+            sort_key("a,b", &SortOptions::default()).unwrap(),
+            UzbekCollator::new().sortable_key("a,b")
+        );
     }
 
     #[test]
-    fn usort_test() {
-        assert_eq!(usort("čiroyli", "čilonzor").unwrap(), 1);
-        assert_eq!(usort("čiroyli", "čiroyli").unwrap(), 0);
-        assert_eq!(usort("čilonzor", "čiroyli").unwrap(), -1);
+    fn sort_sortable_test() {
+        let options = SortOptions::default();
+
+        assert_eq!(
+            sort_sortable("ad ab  aa\nab", &options).unwrap(),
+            "aa ab ab ad"
+        );
+        assert_eq!(sort_sortable("", &options).unwrap(), "");
     }
 
     #[test]
     fn get_sorted_text_test() {
         let input = "G‘ozal estafeta chilonzor o'zbek chiroyli";
         let output = String::from("estafeta o‘zbek chilonzor chiroyli G‘ozal");
-        assert_eq!(sort(input).unwrap(), output)
+        assert_eq!(sort(input).unwrap(), output);
+
+        let lower = SortOptions {
+            case_first: CaseFirst::Lower,
+            ..SortOptions::default()
+        };
+        let output = String::from("estafeta o‘zbek G‘ozal chilonzor chiroyli");
+        assert_eq!(sort_with(input, &lower).unwrap(), output);
+
+        let strict = SortOptions {
+            strict: true,
+            ..SortOptions::default()
+        };
+        let output = String::from("estafeta o‘zbek chilonzor chiroyli G‘ozal");
+        assert_eq!(sort_with(input, &strict).unwrap(), output)
     }
 
     #[test]
     fn get_sorted_text_cyr_test() {
         let input = "Ғозал эстафета чилонзор ўзбек чиройли";
         let output = String::from("чилонзор чиройли эстафета ўзбек Ғозал");
-        assert_eq!(sort(input).unwrap(), output)
+        assert_eq!(sort(input).unwrap(), output);
+
+        let strict = SortOptions {
+            strict: true,
+            ..SortOptions::default()
+        };
+        assert_eq!(sort_with(input, &strict).unwrap(), output);
+        assert_eq!(
+            sort_with("ян юз вагон Ян Юз", &strict).unwrap(),
+            "вагон юз ян Юз Ян"
+        );
+    }
+
+    #[test]
+    fn sort_lenient_test() {
+        let input = "olma, nok; 12 3 (uzum) Олма Wi-Fi ыржа";
+        let output = String::from("3 12 nok; olma, (uzum) Олма Wi-Fi ыржа");
+        assert_eq!(sort(input).unwrap(), output);
+
+        let strict = SortOptions {
+            strict: true,
+            ..SortOptions::default()
+        };
+        assert!(matches!(
+            sort_with(input, &strict),
+            Err(KorrektorError::InvalidChar(','))
+        ));
+    }
+
+    #[test]
+    fn sort_options_test() {
+        let input = "Zafar o‘rik zafar Олма olma Anor";
+        assert_eq!(sort(input).unwrap(), "olma zafar o‘rik Anor Zafar Олма");
+        // letters with diacritics are kept as they are
+        assert_eq!(
+            sort("Über SHAHAR ubor Öl olma").unwrap(),
+            "olma ubor Öl Über SHAHAR"
+        );
+
+        let upper = SortOptions {
+            case_first: CaseFirst::Upper,
+            ..SortOptions::default()
+        };
+        assert_eq!(
+            sort_with(input, &upper).unwrap(),
+            "Anor olma Zafar zafar o‘rik Олма"
+        );
+
+        let interleaved = SortOptions {
+            interleave_scripts: true,
+            ..SortOptions::default()
+        };
+        assert_eq!(
+            sort_with("чой Шаҳар sabzi olma олма", &interleaved).unwrap(),
+            "olma олма sabzi чой Шаҳар"
+        );
+
+        let reversed = SortOptions {
+            descending: true,
+            ..SortOptions::default()
+        };
+        assert_eq!(
+            sort_with("olma 2 Olma nok olma", &reversed).unwrap(),
+            "Olma olma olma nok 2"
+        );
+
+        let unique = SortOptions {
+            unique: true,
+            separator: Separator::Newline,
+            ..SortOptions::default()
+        };
+        assert_eq!(
+            sort_with("o'zbek Olma olma o‘zbek olma O‘zbek", &unique).unwrap(),
+            "olma\no‘zbek\nOlma\nO‘zbek"
+        );
+
+        // strict mode sorts the old way
+        let strict = SortOptions {
+            strict: true,
+            ..upper
+        };
+        assert_eq!(sort_with("Anor anor", &strict).unwrap(), "anor Anor");
     }
 }
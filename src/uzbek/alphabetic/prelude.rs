@@ -1,10 +1,14 @@
-pub(super) const CHAR_ORDER: &[&str] = &[
+pub(crate) const CHAR_ORDER: &[&str] = &[
     "ê", "a", "b", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t", "u", "v", "x", "y", "z", "ŏ", "ğ", "š", "č",
     "Ê", "A", "B", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S", "T", "U", "V", "X", "Y", "Z", "Ŏ", "Ğ", "Š", "Č",
-    "а", "б", "д", "г", "д", "е", "ё", "ж", "з", "и", "й", "к", "л", "м", "н", "о", "п", "р", "с", "т", "у", "ф", "х", "ц", "ч", "ш", "ъ", "ь", "э", "я", "ю", "ў", "қ", "ғ", "ҳ",
-    "А", "Б", "Д", "Г", "Д", "Е", "Ё", "Ж", "З", "И", "Й", "К", "Л", "М", "Н", "О", "П", "Р", "С", "Т", "У", "Ф", "Х", "Ц", "Ч", "Ш", "Ъ", "Ь", "Э", "Ю", "Я", "Ў", "Қ", "Ғ", "Ҳ"
+    "а", "б", "в", "г", "д", "е", "ё", "ж", "з", "и", "й", "к", "л", "м", "н", "о", "п", "р", "с", "т", "у", "ф", "х", "ц", "ч", "ш", "ъ", "ь", "э", "ю", "я", "ў", "қ", "ғ", "ҳ",
+    "А", "Б", "В", "Г", "Д", "Е", "Ё", "Ж", "З", "И", "Й", "К", "Л", "М", "Н", "О", "П", "Р", "С", "Т", "У", "Ф", "Х", "Ц", "Ч", "Ш", "Ъ", "Ь", "Э", "Ю", "Я", "Ў", "Қ", "Ғ", "Ҳ"
 ];
 
+// placeholders of SH and CH, private use characters that do not occur in text
+pub(super) const SH: char = '\u{E000}';
+pub(super) const CH: char = '\u{E001}';
+
 pub(super) const TO_SORT: &[(&str, &str)] = &[
     ("G[ʻʼ'‘’‛′ʽ`]", "Ğ"),
     ("g[ʻʼ'‘’‛′ʽ`]", "ğ"),
@@ -12,10 +16,10 @@ pub(super) const TO_SORT: &[(&str, &str)] = &[
     ("o[ʻʼ'‘’‛′ʽ`]", "ŏ"),
     ("ʻ|ʼ|'|‘|’|‛|′|ʽ|`", "ʼ"),
     ("Sh", "Š"),
-    ("SH", "Ö"),
+    ("SH", "\u{E000}"),
     ("sh", "š"),
     ("Ch", "Č"),
-    ("CH", "Ü"),
+    ("CH", "\u{E001}"),
     ("ch", "č")
 ];
 
@@ -25,9 +29,18 @@ pub(super) const FROM_SORT: &[(&str, &str)] = &[
     ("Ŏ", "O‘"),
     ("ŏ", "o‘"),
     ("Š", "Sh"),
-    ("Ö", "SH"),
+    ("\u{E000}", "SH"),
     ("š", "sh"),
     ("Č", "Ch"),
-    ("Ü", "CH"),
+    ("\u{E001}", "CH"),
     ("č", "ch")
-];
\ No newline at end of file
+];
+
+// latin letters with diacritics that are not a part of the alphabet and their base letters
+pub(super) const DIACRITICS: &[(char, char)] = &[
+    ('à', 'a'), ('á', 'a'), ('â', 'a'), ('ã', 'a'), ('ä', 'a'), ('å', 'a'),
+    ('ç', 'c'), ('è', 'e'), ('é', 'e'), ('ë', 'e'), ('ì', 'i'), ('í', 'i'),
+    ('î', 'i'), ('ï', 'i'), ('ñ', 'n'), ('ò', 'o'), ('ó', 'o'), ('ô', 'o'),
+    ('õ', 'o'), ('ö', 'o'), ('ù', 'u'), ('ú', 'u'), ('û', 'u'), ('ü', 'u'),
+    ('ý', 'y'), ('ÿ', 'y'), ('ş', 's'),
+];
@@ -1,17 +1,18 @@
 use crate::utils::wrappers;
+use crate::uzbek::Alphabet;
 use fancy_regex;
 use korrektor_rspell::BadWord;
 use prelude::*;
 use regex;
 use serde::{Deserialize, Serialize};
 
-mod prelude;
+pub(crate) mod prelude;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct KorrektorBadWord {
-    misspelled: String,
-    position: usize,
-    suggestions: Vec<String>,
+    pub misspelled: String,
+    pub position: usize,
+    pub suggestions: Vec<String>,
 }
 
 pub fn remove_modifiers(text: &str) -> String {
@@ -41,7 +42,9 @@ pub fn correct(text: &str) -> String {
         input = re.replace_all(&input, replacement).to_string();
     }
 
-    let re = regex::Regex::new(r"(\d+)-(январ|феврал|март|апрел|май|июн|июл|август|сентябр|октябр|ноябр|декабр|ЯНВАР|ФЕВРАЛ|МАРТ|АПРЕЛ|МАЙ|ИЮН|ИЮЛ|АВГУСТ|СЕНТЯБР|ОКТЯБР|НОЯБР|ДЕКАБР)").unwrap();
+    let re = regex::Regex::new(&format!(r"(\d+)\s+({})", months_pattern(&MONTHS))).unwrap();
+    input = re.replace_all(&input, "${1}-${2}").to_string();
+    let re = regex::Regex::new(&format!(r"(\d+)-({})", months_pattern(&MONTHS_CYRILLIC))).unwrap();
     input = re.replace_all(&input, "${1} ${2}").to_string();
     let re = regex::Regex::new(r"(\d+)-(йил|ЙИЛ|й\.)").unwrap();
     input = re.replace_all(&input, "${1} ${2}").to_string();
@@ -53,21 +56,30 @@ pub fn correct(text: &str) -> String {
     input
 }
 
-pub fn get_correction_suggestions(text: &str, lang: &str) -> Vec<KorrektorBadWord> {
+// month names in lower and upper case
+fn months_pattern(months: &[&str]) -> String {
+    months
+        .iter()
+        .flat_map(|month| [month.to_string(), month.to_uppercase()])
+        .collect::<Vec<String>>()
+        .join("|")
+}
+
+pub fn get_correction_suggestions(text: &str, alphabet: Alphabet) -> Vec<KorrektorBadWord> {
     // wrap url, mail addresses, and usernames
     let input: String = wrappers::get_wrapped_text(text);
 
-    check_wrapped_text(&input, lang)
+    check_wrapped_text(&input, alphabet)
 }
 
-fn check_wrapped_text(text: &str, lang: &str) -> Vec<KorrektorBadWord> {
+fn check_wrapped_text(text: &str, alphabet: Alphabet) -> Vec<KorrektorBadWord> {
     let mut corrections: Vec<KorrektorBadWord> = vec![];
 
     let re = fancy_regex::Regex::new("([^〈〉](?![^〈]*〉))+").unwrap();
     for capture in re.captures_iter(text) {
         let capture = capture.unwrap()[0].to_string();
 
-        let correction = check_spelling(&capture, lang);
+        let correction = check_spelling(&capture, alphabet);
 
         for rspell_word in correction {
             let korrektor = KorrektorBadWord {
@@ -83,11 +95,10 @@ fn check_wrapped_text(text: &str, lang: &str) -> Vec<KorrektorBadWord> {
     corrections
 }
 
-fn check_spelling<'a>(text: &'a str, lang: &str) -> Vec<BadWord<'a>> {
-    let language = match lang {
-        "cyr" => "uz-cyr",
-        "lat" => "uz-lat",
-        _ => "uz-lat",
+fn check_spelling<'a>(text: &'a str, alphabet: Alphabet) -> Vec<BadWord<'a>> {
+    let language = match alphabet {
+        Alphabet::Cyrillic => "uz-cyr",
+        Alphabet::Latin => "uz-lat",
     };
 
     let spell = korrektor_rspell::Spell::new(language).unwrap();
@@ -144,14 +155,14 @@ mod as_tests {
             ],
         }];
 
-        assert_eq!(check_wrapped_text("chroyli", "lat"), errors_lat);
-        assert_eq!(check_wrapped_text("chroyli", ""), errors_lat);
-        assert_eq!(check_wrapped_text("чройли", "cyr"), errors_cyr);
+        assert_eq!(check_wrapped_text("chroyli", Alphabet::Latin), errors_lat);
+        assert_eq!(check_wrapped_text("chroyli", Alphabet::default()), errors_lat);
+        assert_eq!(check_wrapped_text("чройли", Alphabet::Cyrillic), errors_cyr);
     }
 
     #[test]
     fn check_wrapped_text_test() {
-        assert_eq!(check_wrapped_text("〈@hello〉 〈〈nyan@mail.uz〉〉 〈〈nya@mail.uz〉〉 〈https://nyan.com〉 go'zal 〈@crystalny〉", "lat"), vec![]);
+        assert_eq!(check_wrapped_text("〈@hello〉 〈〈nyan@mail.uz〉〉 〈〈nya@mail.uz〉〉 〈https://nyan.com〉 go'zal 〈@crystalny〉", Alphabet::Latin), vec![]);
     }
 
     #[test]
@@ -159,7 +170,7 @@ mod as_tests {
         assert_eq!(
             get_correction_suggestions(
                 "@hello nyan@mail.uz nya@mail.uz https://nyan.com go'zal @crystalny",
-                "lat"
+                Alphabet::Latin
             ),
             vec![]
         );
@@ -1,4 +1,4 @@
-pub const CORRECT: [(&str, &str); 9] = [
+pub const CORRECT: [(&str, &str); 8] = [
     ("ʻ|ʼ|'|‘|’|‛|′|ʽ|`", "ʼ"),
     ("G[ʻʼ'‘’‛′ʽ`]", "G‘"),
     ("g[ʻʼ'‘’‛′ʽ`]", "g‘"),
@@ -6,6 +6,44 @@ pub const CORRECT: [(&str, &str); 9] = [
     ("o[ʻʼ'‘’‛′ʽ`]", "o‘"),
     ("([^БВГДЕЁЖЗИЙКЛМНПРСТФХЦЧШЪЫЬЭЮЯЎҚҒҲбвгдеёжзийклмнпрстфхцчшъыьэюяўқғҳ])Яна-да([^БВГДЕЁЖЗИЙКЛМНПРСТФХЦЧШЪЫЬЭЮЯЎҚҒҲбвгдеёжзийклмнпрстфхцчшъыьэюяўқғҳ])", "${1}Янада${2}"),
     ("([^БВГДЕЁЖЗИЙКЛМНПРСТФХЦЧШЪЫЬЭЮЯЎҚҒҲбвгдеёжзийклмнпрстфхцчшъыьэюяўқғҳ])яна-да([^БВГДЕЁЖЗИЙКЛМНПРСТФХЦЧШЪЫЬЭЮЯЎҚҒҲбвгдеёжзийклмнпрстфхцчшъыьэюяўқғҳ])", "${1}янада${2}"),
-    ("(\\d+)\\s+(yanvar|fevral|mart|aprel|may|iyun|iyul|avgust|sentyabr|oktyabr|noyabr|dekabr|YANVAR|FEVRAL|MART|APREL|MAY|IYUN|IYUL|AVGUST|SENTYABR|OKTYABR|NOYABR|DEKABR)", "${1}-${2}"),
     ("(\\d{3,4})\\s+(yil|YIL|y\\.)", "${1}-${2}"),
 ];
+
+pub(crate) const MONTHS: [&str; 12] = [
+    "yanvar", "fevral", "mart", "aprel", "may", "iyun",
+    "iyul", "avgust", "sentyabr", "oktyabr", "noyabr", "dekabr",
+];
+
+pub(crate) const MONTHS_CYRILLIC: [&str; 12] = [
+    "январ", "феврал", "март", "апрел", "май", "июн",
+    "июл", "август", "сентябр", "октябр", "ноябр", "декабр",
+];
+
+// abbreviations followed by a period that does not end the sentence (1441 y., t.y.),
+// the only list of them in the crate, add new ones here
+pub(crate) const ABBREVIATIONS: &[&str] = &[
+    "y",
+    "yy",
+    "t.y",
+    "sh",
+    "vil",
+    "tum",
+    "ko‘ch",
+    "mah",
+    "prof",
+    "dots",
+    "akad",
+    "t.f.n",
+    "f.f.n",
+    "ш",
+    "вил",
+    "тум",
+    "кўч",
+    "маҳ",
+    "проф",
+    "доц",
+    "акад",
+    "т.й",
+    "й",
+    "йй",
+];
@@ -1,6 +1,9 @@
 //! Functionality with Uzbek-specific implementations.
+pub mod alphabet;
 pub mod alphabetic;
 pub mod corrector;
 pub mod number;
 pub mod tokenize;
 pub mod transliterator;
+
+pub use alphabet::{detect_script, Alphabet, ScriptReport};
@@ -0,0 +1,311 @@
+//! Numbers with a meaning: money, percentages, time and dates.
+use super::helper::{Conversion, INTEGER, SIGN};
+use super::{float_to_word_with, in_alphabet, integer_words, to_ordinal, SCALES};
+use crate::error::KorrektorError;
+use crate::uzbek::corrector::prelude::{MONTHS, MONTHS_CYRILLIC};
+use crate::uzbek::Alphabet;
+use fancy_regex::Regex;
+use once_cell::sync::Lazy;
+
+// symbols and codes of currencies along with the names of their units and subunits
+const CURRENCIES: &[(&[&str], &str, &str)] = &[
+    (&["$", "USD"], "dollar", "sent"),
+    (&["€", "EUR"], "yevro", "sent"),
+    (&["£", "GBP"], "funt sterling", "pens"),
+    (&["₽", "RUB"], "rubl", "kopeyka"),
+    (
+        &["UZS", "so‘m", "soʻm", "so’m", "so'm", "so`m", "сўм"],
+        "so‘m",
+        "tiyin",
+    ),
+];
+
+// an amount of money or a percentage, a unit next to the number tells "5 000 so‘m"
+// from two numbers
+fn amount() -> String {
+    format!(r"(?:[0-9]{{1,3}}(?: [0-9]{{3}})+(?![0-9])|{INTEGER})(?:\.[0-9]+)?")
+}
+
+// symbols of currencies as alternatives of a pattern, prefixes only if they are not words
+fn symbols(prefix: bool) -> String {
+    CURRENCIES
+        .iter()
+        .flat_map(|(symbols, _, _)| symbols.iter())
+        // only $12, not so‘m 12
+        .filter(|symbol| !prefix || !symbol.chars().any(char::is_alphabetic))
+        .map(|symbol| fancy_regex::escape(symbol).to_string())
+        .collect::<Vec<String>>()
+        .join("|")
+}
+
+// latin and cyrillic names of months in lower and upper case as alternatives of a pattern
+fn months() -> String {
+    MONTHS
+        .iter()
+        .chain(MONTHS_CYRILLIC.iter())
+        .flat_map(|month| [month.to_string(), month.to_uppercase()])
+        .collect::<Vec<String>>()
+        .join("|")
+}
+
+// 12.50 $, 5 000 so‘m
+static MONEY: Lazy<Regex> = Lazy::new(|| {
+    let amount = amount();
+    let symbols = symbols(false);
+    Regex::new(&format!(
+        r"(?<![0-9.,])({amount})\s?({symbols})(?![\p{{L}}])"
+    ))
+    .unwrap()
+});
+// $12.50
+static PREFIXED_MONEY: Lazy<Regex> = Lazy::new(|| {
+    let amount = amount();
+    let symbols = symbols(true);
+    Regex::new(&format!(
+        r"(?<![\p{{L}}0-9])({symbols})\s?({amount})(?![0-9])"
+    ))
+    .unwrap()
+});
+// 15%, -2.5 %
+static PERCENT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(&format!(r"({SIGN}{})\s?%", amount())).unwrap());
+// 12:30, 08:05:10
+static TIME: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?<![0-9:.])([01]?[0-9]|2[0-3]):([0-5][0-9])(?::([0-5][0-9]))?(?![0-9:])").unwrap()
+});
+// 13.01.2022
+static DATE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?<![0-9.])(0?[1-9]|[12][0-9]|3[01])\.(0?[1-9]|1[0-2])\.([0-9]{4})(?![0-9]|\.[0-9])",
+    )
+    .unwrap()
+});
+// 13 yanvar
+static DAY: Lazy<Regex> = Lazy::new(|| {
+    let months = months();
+    Regex::new(&format!(
+        r"(?<![0-9.,])([0-9]{{1,2}})\s+({months})(?![\p{{L}}])"
+    ))
+    .unwrap()
+});
+// 2022 yil
+static YEAR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?<![0-9.,])([0-9]{3,4})\s+(yil|YIL|йил|ЙИЛ)(?![\p{L}])").unwrap());
+// 2022 y.
+static SHORT_YEAR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?<![0-9.,])([0-9]{3,4})\s+(y|й)\.").unwrap());
+
+/// Money, percentages, time and dates along with their words.
+pub(super) fn conversions(alphabet: Alphabet) -> Vec<Conversion> {
+    vec![
+        (
+            &MONEY,
+            Box::new(move |captures| money(&captures[1], &captures[2], alphabet)),
+        ),
+        (
+            &PREFIXED_MONEY,
+            Box::new(move |captures| money(&captures[2], &captures[1], alphabet)),
+        ),
+        (
+            &PERCENT,
+            Box::new(move |captures| Ok(in_alphabet(words(&captures[1])? + " foiz", alphabet))),
+        ),
+        (
+            &TIME,
+            Box::new(move |captures| {
+                time(
+                    &captures[1],
+                    &captures[2],
+                    captures.get(3).map(|s| s.as_str()),
+                    alphabet,
+                )
+            }),
+        ),
+        (
+            &DATE,
+            Box::new(move |captures| {
+                let month: usize = captures[2].parse().unwrap();
+                let month = match alphabet {
+                    Alphabet::Latin => MONTHS[month - 1],
+                    Alphabet::Cyrillic => MONTHS_CYRILLIC[month - 1],
+                };
+
+                Ok(format!(
+                    "{} {} {} {month}",
+                    to_ordinal(&captures[3], alphabet)?,
+                    in_alphabet(String::from("yil"), alphabet),
+                    to_ordinal(&captures[1], alphabet)?
+                ))
+            }),
+        ),
+        (
+            &DAY,
+            Box::new(move |captures| {
+                Ok(format!(
+                    "{} {}",
+                    to_ordinal(&captures[1], alphabet)?,
+                    &captures[2]
+                ))
+            }),
+        ),
+        (
+            &YEAR,
+            Box::new(move |captures| {
+                Ok(format!(
+                    "{} {}",
+                    to_ordinal(&captures[1], alphabet)?,
+                    &captures[2]
+                ))
+            }),
+        ),
+        (
+            &SHORT_YEAR,
+            Box::new(move |captures| {
+                Ok(format!(
+                    "{} {}",
+                    to_ordinal(&captures[1], alphabet)?,
+                    in_alphabet(String::from("yil"), alphabet)
+                ))
+            }),
+        ),
+    ]
+}
+
+// latin words of an integer or a floating-point number
+fn words(number: &str) -> Result<String, KorrektorError> {
+    if number.contains('.') {
+        float_to_word_with(number, Alphabet::Latin, &SCALES)
+    } else {
+        integer_words(number, &SCALES)
+    }
+}
+
+// "o‘n ikki dollar ellik sent", subunits only for amounts with up to two decimals
+fn money(amount: &str, symbol: &str, alphabet: Alphabet) -> Result<String, KorrektorError> {
+    let (_, unit, subunit) = CURRENCIES
+        .iter()
+        .find(|(symbols, _, _)| symbols.contains(&symbol))
+        .unwrap();
+
+    let words = match amount.rsplit_once('.') {
+        Some((integer, cents)) if cents.len() <= 2 => {
+            let cents = format!("{cents:0<2}");
+            let integer = format!("{} {unit}", integer_words(integer, &SCALES)?);
+
+            if cents == "00" {
+                integer
+            } else {
+                format!("{integer} {} {subunit}", integer_words(&cents, &SCALES)?)
+            }
+        }
+        _ => format!("{} {unit}", words(amount)?),
+    };
+
+    Ok(in_alphabet(words, alphabet))
+}
+
+// "o‘n ikki yu o‘ttiz daqiqa", hours alone on the hour
+fn time(
+    hours: &str,
+    minutes: &str,
+    seconds: Option<&str>,
+    alphabet: Alphabet,
+) -> Result<String, KorrektorError> {
+    let mut words = integer_words(hours, &SCALES)?;
+
+    if minutes != "00" || seconds.is_some() {
+        // -yu after a vowel, -u after a consonant
+        let conjunction = if words.ends_with(['a', 'e', 'i', 'o', 'u']) {
+            "yu"
+        } else {
+            "u"
+        };
+        words = format!(
+            "{words} {conjunction} {} daqiqa",
+            integer_words(minutes, &SCALES)?
+        );
+    }
+
+    if let Some(seconds) = seconds {
+        words = format!("{words} {} soniya", integer_words(seconds, &SCALES)?);
+    }
+
+    Ok(in_alphabet(words, alphabet))
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+    use crate::uzbek::number::helper;
+
+    fn convert_entities(text: &str, alphabet: Alphabet) -> Result<String, KorrektorError> {
+        helper::convert(text, &conversions(alphabet))
+    }
+
+    fn convert(text: &str) -> String {
+        convert_entities(text, Alphabet::Latin).unwrap()
+    }
+
+    #[test]
+    fn money_test() {
+        assert_eq!(convert("$12.50"), "o‘n ikki dollar ellik sent");
+        assert_eq!(
+            convert("$12.5 va € 3"),
+            "o‘n ikki dollar ellik sent va uch yevro"
+        );
+        assert_eq!(convert("12.05 USD"), "o‘n ikki dollar besh sent");
+        assert_eq!(convert("5 000 so‘m"), "besh ming so‘m");
+        assert_eq!(
+            convert("1.125 $"),
+            "bir butun mingdan bir yuz yigirma besh dollar"
+        );
+        assert_eq!(
+            convert_entities("2 500 сўм", Alphabet::Cyrillic).unwrap(),
+            "икки минг беш юз сўм"
+        );
+        // not a currency
+        assert_eq!(convert("5 USDT"), "5 USDT");
+        assert_eq!(convert("1000 so‘m 5 dona"), "bir ming so‘m 5 dona");
+    }
+
+    #[test]
+    fn percent_test() {
+        assert_eq!(convert("15%"), "o‘n besh foiz");
+        assert_eq!(convert("-2.5 %"), "minus ikki butun o‘ndan besh foiz");
+    }
+
+    #[test]
+    fn time_test() {
+        assert_eq!(convert("12:30"), "o‘n ikki yu o‘ttiz daqiqa");
+        assert_eq!(convert("soat 8:05 da"), "soat sakkiz u besh daqiqa da");
+        assert_eq!(convert("09:00"), "to‘qqiz");
+        assert_eq!(
+            convert("23:59:05"),
+            "yigirma uch u ellik to‘qqiz daqiqa besh soniya"
+        );
+        // not a time
+        assert_eq!(convert("25:30 1:2"), "25:30 1:2");
+    }
+
+    #[test]
+    fn date_test() {
+        assert_eq!(
+            convert("13.01.2022"),
+            "ikki ming yigirma ikkinchi yil o‘n uchinchi yanvar"
+        );
+        assert_eq!(
+            convert("2022 yil 13 yanvar"),
+            "ikki ming yigirma ikkinchi yil o‘n uchinchi yanvar"
+        );
+        assert_eq!(
+            convert("1991 y. 31 AVGUST"),
+            "bir ming to‘qqiz yuz to‘qson birinchi yil o‘ttiz birinchi AVGUST"
+        );
+        assert_eq!(
+            convert_entities("01.09.1991", Alphabet::Cyrillic).unwrap(),
+            "бир минг тўққиз юз тўқсон биринчи йил биринчи сентябр"
+        );
+        // not a date
+        assert_eq!(convert("32.01.2022 1.2.3"), "32.01.2022 1.2.3");
+    }
+}
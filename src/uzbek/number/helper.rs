@@ -1,103 +1,614 @@
 use crate::error::KorrektorError;
-use crate::utils::pcre as ipcre;
-use crate::uzbek::number::{float_to_word, integer_to_word};
+use crate::uzbek::alphabet::APOSTROPHES;
+use crate::uzbek::number::{
+    float_to_word, fraction_prefix, integer_to_word, prelude, to_ordinal, SCALES,
+};
+use crate::uzbek::tokenize::{Token, TokenKind};
+use crate::uzbek::transliterator;
+use crate::uzbek::Alphabet;
+use fancy_regex::{Captures, Regex};
+use once_cell::sync::Lazy;
+use std::cmp::Reverse;
+use std::ops::Range;
 
-pub(super) fn is_valid_integer(number: &str) -> bool {
-    let re = regex::Regex::new(r"^(\d+)$").unwrap();
+// sign only at the start of a word, the hyphen of "5-7" is not a minus
+pub(super) const SIGN: &str = r"(?:(?<![\p{L}0-9])[-+−])?";
+// digits with thousands separators (1 000 000, 1,000,000, 12\u{a0}500) or without them,
+// plain spaces only between two groups or more as "3 100 ta" are two numbers
+pub(super) const INTEGER: &str = concat!(
+    r"(?:[0-9]{1,3}(?:(?:,[0-9]{3})+|(?:[\u{a0}\u{202f}][0-9]{3})+|(?: [0-9]{3}){2,})(?![0-9])",
+    r"|[0-9]+)"
+);
+// an integer on its own, where a single group after a space is not another number
+static SINGLE_INTEGER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:[0-9]{1,3}(?:(?:,[0-9]{3})+|(?:[ \u{a0}\u{202f}][0-9]{3})+)(?![0-9])|[0-9]+)$")
+        .unwrap()
+});
 
-    re.is_match(number)
+// fraction prefixes split into words along with the number of digits they stand for
+static FRACTION_PREFIXES: Lazy<Vec<(usize, Vec<String>)>> = Lazy::new(|| {
+    (1..)
+        .map_while(|digits| Some((digits, fraction_prefix(digits, &SCALES)?)))
+        .map(|(digits, prefix)| (digits, prefix.split(' ').map(normalize_word).collect()))
+        .collect()
+});
+
+fn is_valid_integer(number: &str) -> bool {
+    SINGLE_INTEGER.is_match(number).unwrap_or(false)
 }
 
-pub(super) fn is_valid_float(number: &str) -> bool {
-    let re = regex::Regex::new(r"^(\d+\.\d+)$").unwrap();
+// sign word and digits of an integer with no separators and leading zeros
+pub(super) fn split_integer(number: &str) -> Option<(Option<&'static str>, String)> {
+    let (sign, digits) = match number.chars().next()? {
+        '-' | '−' => (Some("minus"), &number[number.chars().next()?.len_utf8()..]),
+        '+' => (Some("plyus"), &number[1..]),
+        _ => (None, number),
+    };
+
+    if !is_valid_integer(digits) {
+        return None;
+    }
 
-    re.is_match(number)
+    let digits: String = digits.chars().filter(char::is_ascii_digit).collect();
+    Some((sign, trim_zeros(&digits).to_string()))
 }
 
-// wrap ips to preserve from transforming into word equivalent
-pub(super) fn wrap_ips(input: &str) -> String {
-    // ipv4 addresses regex
-    let mut re = pcre::Pcre::compile(r"\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}").unwrap();
-    let matches_ipv4 = re.matches(input);
+pub(super) fn trim_zeros(digits: &str) -> &str {
+    match digits.trim_start_matches('0') {
+        "" => "0",
+        digits => digits,
+    }
+}
 
-    // ipv6 addresses regex
-    let mut re =
-        pcre::Pcre::compile
-            (r"(([0-9a-fA-F]{1,4}:){7,7}[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,7}:|\
-            ([0-9a-fA-F]{1,4}:){1,6}:[0-9a-fA-F]{1,4}|([0-9a-fA-F]{1,4}:){1,5}(:[0-9a-fA-F]{1,4}){1,2}|\
-            ([0-9a-fA-F]{1,4}:){1,4}(:[0-9a-fA-F]{1,4}){1,3}|([0-9a-fA-F]{1,4}:){1,3}(:[0-9a-fA-F]{1,4}){1,4}|\
-            ([0-9a-fA-F]{1,4}:){1,2}(:[0-9a-fA-F]{1,4}){1,5}|[0-9a-fA-F]{1,4}:((:[0-9a-fA-F]{1,4}){1,6})|:((:[0-9a-fA-F]{1,4}){1,7}|:)|\
-            fe80:(:[0-9a-fA-F]{0,4}){0,4}%[0-9a-zA-Z]{1,}|::(ffff(:0{1,4}){0,1}:){0,1}((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\.){3,3}(25[0-5]|\
-            (2[0-4]|1{0,1}[0-9]){0,1}[0-9])|([0-9a-fA-F]{1,4}:){1,4}:((25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9])\.){3,3}(25[0-5]|(2[0-4]|1{0,1}[0-9]){0,1}[0-9]))").unwrap();
-    let matches_ipv6 = re.matches(input);
+// IPv4 addresses
+pub(super) static IPV4: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?<![0-9.])[0-9]{1,3}\.[0-9]{1,3}\.[0-9]{1,3}\.[0-9]{1,3}(?![0-9]|\.[0-9])")
+        .unwrap()
+});
+// IPv6 addresses, the lookahead makes the alternatives match up to the end of an address
+pub(super) static IPV6: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(concat!(
+        r"(?:(?:[0-9a-fA-F]{1,4}:){7}[0-9a-fA-F]{1,4}|(?:[0-9a-fA-F]{1,4}:){1,7}:|",
+        r"(?:[0-9a-fA-F]{1,4}:){1,6}:[0-9a-fA-F]{1,4}|(?:[0-9a-fA-F]{1,4}:){1,5}(?::[0-9a-fA-F]{1,4}){1,2}|",
+        r"(?:[0-9a-fA-F]{1,4}:){1,4}(?::[0-9a-fA-F]{1,4}){1,3}|(?:[0-9a-fA-F]{1,4}:){1,3}(?::[0-9a-fA-F]{1,4}){1,4}|",
+        r"(?:[0-9a-fA-F]{1,4}:){1,2}(?::[0-9a-fA-F]{1,4}){1,5}|[0-9a-fA-F]{1,4}:(?::[0-9a-fA-F]{1,4}){1,6}|",
+        r":(?:(?::[0-9a-fA-F]{1,4}){1,7}|:)|fe80:(?::[0-9a-fA-F]{0,4}){0,4}%[0-9a-zA-Z]+|",
+        r"::(?:ffff(?::0{1,4})?:)?(?:(?:25[0-5]|(?:2[0-4]|1?[0-9])?[0-9])\.){3}(?:25[0-5]|(?:2[0-4]|1?[0-9])?[0-9])|",
+        r"(?:[0-9a-fA-F]{1,4}:){1,4}:(?:(?:25[0-5]|(?:2[0-4]|1?[0-9])?[0-9])\.){3}(?:25[0-5]|(?:2[0-4]|1?[0-9])?[0-9]))",
+        r"(?![0-9a-fA-F:])"
+    ))
+    .unwrap()
+});
+// phone numbers in the full (998901234567, 998 90 123-45-67)
+// and in the local form ((90) 123-45-67, 90 123 45 67)
+pub(super) static PHONE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?<![0-9])(?:998[ -]?\(?[0-9]{2}\)?[ -]?[0-9]{3}[ -]?[0-9]{2}[ -]?[0-9]{2}|(?:\([0-9]{2}\) ?|[0-9]{2}[ -])[0-9]{3}[ -][0-9]{2}[ -][0-9]{2})(?![0-9])").unwrap()
+});
+// passport numbers (AA1234567, AB 1234567)
+pub(super) static PASSPORT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?<![\p{L}0-9])[A-Z]{2} ?[0-9]{7}(?![0-9])").unwrap());
+// taxpayer numbers after the name of them (INN 123456789)
+pub(super) static TAX_ID: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)(?<!\p{L})(?:INN|STIR|ИНН|СТИР)[ :]*[0-9]{9}(?![0-9])").unwrap());
+// personal numbers of 14 digits after the name of them (PINFL 31234567890123),
+// any other number of 14 digits is just a number
+pub(super) static PERSONAL_ID: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(?<!\p{L})(?:PINFL|JSHSHIR|ПИНФЛ|ЖШШИР)[ :]*[3-6][0-9]{13}(?![0-9])").unwrap()
+});
+// card numbers of 16 digits in groups of four (8600 1234 5678 9012),
+// 16 digits with no spaces are just a number
+pub(super) static CARD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?<![0-9])[0-9]{4}([ -])[0-9]{4}\1[0-9]{4}\1[0-9]{4}(?![0-9])").unwrap()
+});
+// six-digit postal codes of Uzbekistan (100000 to 299999)
+pub(super) static POSTAL_CODE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?<![0-9.,])[12][0-9]{5}(?![0-9]|[.,][0-9])").unwrap());
+// versions (v1.2, 1.2.3, 2.0.1-beta), dates like 13.01.2022 are not versions
+pub(super) static VERSION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?<![\p{L}0-9.])(?:[vV][0-9]+(?:\.[0-9]+)*|(?![0-9]{1,2}\.[0-9]{1,2}\.[0-9]{4}(?![0-9]))[0-9]+(?:\.[0-9]+){2,})(?:-[0-9A-Za-z.]+)?(?![0-9])").unwrap()
+});
+// dates (13.01.2022, 13/01/2022, 2022-01-13)
+pub(super) static DATE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?<![0-9.])(?:[0-9]{1,2}([./])[0-9]{1,2}\1[0-9]{4}|[0-9]{4}-[0-9]{2}-[0-9]{2})(?![0-9]|\.[0-9])").unwrap()
+});
 
-    // wrapping with 〈〉 brackets
-    let result = ipcre::wrap_matches(input, matches_ipv4);
-    ipcre::wrap_matches(&result, matches_ipv6)
+// byte ranges of the text matched by any of the patterns, sorted and merged where they overlap
+pub(super) fn protected_spans(text: &str, patterns: &[&Regex]) -> Vec<Range<usize>> {
+    let mut spans: Vec<Range<usize>> = patterns
+        .iter()
+        .flat_map(|re| {
+            re.find_iter(text)
+                .filter_map(Result::ok)
+                .map(|found| found.range())
+                .collect::<Vec<Range<usize>>>()
+        })
+        .collect();
+    spans.sort_by_key(|span| span.start);
+
+    let mut merged: Vec<Range<usize>> = vec![];
+    for span in spans {
+        match merged.last_mut() {
+            Some(last) if span.start <= last.end => last.end = last.end.max(span.end),
+            _ => merged.push(span),
+        }
+    }
+
+    merged
+}
+
+/// A pattern along with the words its matches are replaced with.
+pub(super) type Conversion = (
+    &'static Regex,
+    Box<dyn Fn(&Captures) -> Result<String, KorrektorError>>,
+);
+
+// replaces the matches of all the patterns in a single pass from left to right,
+// every pattern is matched against the text as it is; where matches overlap the
+// leftmost one wins, then the longest one, then the one of the earlier pattern
+pub(super) fn convert(text: &str, conversions: &[Conversion]) -> Result<String, KorrektorError> {
+    let mut found: Vec<(usize, Captures)> = conversions
+        .iter()
+        .enumerate()
+        .flat_map(|(index, (re, _))| {
+            re.captures_iter(text)
+                .filter_map(Result::ok)
+                .map(move |captures| (index, captures))
+        })
+        .collect();
+    found.sort_by_key(|(index, captures)| {
+        let whole = captures.get(0).unwrap();
+        (whole.start(), Reverse(whole.end()), *index)
+    });
+
+    let mut result = String::with_capacity(text.len());
+    let mut last = 0;
+
+    for (index, captures) in found {
+        let whole = captures.get(0).unwrap();
+        if whole.start() < last {
+            continue;
+        }
+
+        result.push_str(&text[last..whole.start()]);
+        result.push_str(&(conversions[index].1)(&captures)?);
+        last = whole.end();
+    }
+    result.push_str(&text[last..]);
+
+    Ok(result)
 }
 
-pub(super) fn wrap_phones(input: &str) -> String {
-    let mut re = pcre::Pcre::compile(
-        "(998)(90|91|93|94|95|97|98|99|50|88|69|70|71|72|77|33)([0-9]{3})([0-9]{2})([0-9]{2})",
+// 5-sinf, 3-chi, 3-inchi
+static ORDINAL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?<![\d.,])(\d+)-(?:(?:(?:i?n)?chi|(?:и?н)?чи)(?!\p{L})|(?=\p{L}))").unwrap()
+});
+static FLOAT: Lazy<Regex> = Lazy::new(|| Regex::new(&format!(r"{SIGN}{INTEGER}\.[0-9]+")).unwrap());
+static SIGNED_INTEGER: Lazy<Regex> = Lazy::new(|| Regex::new(&format!("{SIGN}{INTEGER}")).unwrap());
+
+// 5-sinf, 3-chi, 3-inchi into ordinals, the hyphen before a word becomes a space
+pub(super) fn ordinals(alphabet: Alphabet) -> Conversion {
+    (
+        &ORDINAL,
+        Box::new(move |captures| {
+            let ordinal = to_ordinal(&captures[1], alphabet)?;
+
+            if captures[0].ends_with('-') {
+                Ok(ordinal + " ")
+            } else {
+                Ok(ordinal)
+            }
+        }),
+    )
+}
+
+pub(super) fn floats(alphabet: Alphabet) -> Conversion {
+    (
+        &FLOAT,
+        Box::new(move |captures| float_to_word(&captures[0], alphabet)),
     )
-    .unwrap();
-    let matches = re.matches(input);
+}
 
-    ipcre::wrap_matches(input, matches)
+pub(super) fn integers(alphabet: Alphabet) -> Conversion {
+    (
+        &SIGNED_INTEGER,
+        Box::new(move |captures| integer_to_word(&captures[0], alphabet)),
+    )
 }
 
-pub(super) fn convert_floats(text: &str) -> Result<String, KorrektorError> {
-    let mut result = text.to_string();
-    let re = fancy_regex::Regex::new(r"(\d+\.\d+)").unwrap();
+// lowercased latin spelling with apostrophes unified to ', the way WORD_0_TO_90 is written
+pub(super) fn normalize_word(word: &str) -> String {
+    let word = word.to_lowercase();
+    let word = if word
+        .chars()
+        .any(|char| ('\u{0400}'..='\u{04FF}').contains(&char))
+    {
+        transliterator::to_latin(word)
+    } else {
+        word
+    };
+
+    word.chars()
+        .map(|char| {
+            if APOSTROPHES.contains(&char) {
+                '\''
+            } else {
+                char
+            }
+        })
+        .collect()
+}
 
-    for capture in re.captures_iter(text) {
-        let capture = capture.unwrap()[0].to_string();
+// value of a single word from 0 to 90
+fn value(word: &str) -> Option<i64> {
+    prelude::WORD_0_TO_90
+        .iter()
+        .find(|(number, _)| *number == word)
+        .map(|(_, value)| *value as i64)
+}
 
-        result = result.replace(&capture, &float_to_word(&capture)?);
+// power of ten of yuz, ming, million and others
+fn power(word: &str) -> Option<u32> {
+    if word == "yuz" {
+        return Some(2);
     }
 
-    Ok(result)
+    prelude::SCALES
+        .iter()
+        .position(|scale| *scale == word)
+        .map(|index| 3 * (index as u32 + 1))
+}
+
+fn is_number_word(word: &str) -> bool {
+    value(word).is_some()
+        || power(word).is_some()
+        || ["butun", "minus", "plyus"].contains(&word)
+        || FRACTION_PREFIXES
+            .iter()
+            .any(|(_, prefix)| prefix.iter().any(|part| part == word))
 }
 
-pub(super) fn convert_integers(text: &str) -> Result<String, KorrektorError> {
-    let mut result = text.to_string();
-    let re = fancy_regex::Regex::new(r"(\d+)").unwrap();
+// whether the number is negative and the words after the sign
+fn split_sign(words: &[String]) -> (bool, &[String]) {
+    match words.first().map(String::as_str) {
+        Some("minus") => (true, &words[1..]),
+        Some("plyus") => (false, &words[1..]),
+        _ => (false, words),
+    }
+}
 
-    for capture in re.captures_iter(text) {
-        let capture = capture.unwrap()[0].to_string();
+// [unit yuz] [ten] [unit], e.g. "besh yuz o'ttiz ikki"
+fn parse_hundreds(words: &[String], index: &mut usize) -> Option<i64> {
+    let start = *index;
+    let word = |index: usize| words.get(index).map(String::as_str);
+    let unit = |word: Option<&str>| word.and_then(value).filter(|value| (1..=9).contains(value));
+    let mut number = 0;
 
-        result = result.replace(&capture, &integer_to_word(&capture)?);
+    if let (Some(unit), Some("yuz")) = (unit(word(*index)), word(*index + 1)) {
+        number += unit * 100;
+        *index += 2;
+    } else if word(*index) == Some("yuz") {
+        number += 100;
+        *index += 1;
     }
 
-    Ok(result)
+    if let Some(ten) = word(*index).and_then(value).filter(|value| *value >= 10) {
+        number += ten;
+        *index += 1;
+    }
+
+    if let Some(unit) = unit(word(*index)) {
+        number += unit;
+        *index += 1;
+    }
+
+    (*index > start).then_some(number)
+}
+
+// words should be normalized with normalize_word
+pub(super) fn parse_integer(words: &[String]) -> Option<i64> {
+    match split_sign(words) {
+        (true, words) => parse_unsigned(words).map(|number| -number),
+        (false, words) => parse_unsigned(words),
+    }
+}
+
+fn parse_unsigned(words: &[String]) -> Option<i64> {
+    if words.len() == 1 && words[0] == "nol" {
+        return Some(0);
+    }
+
+    let mut index = 0;
+    let mut total: i64 = 0;
+    // multipliers should go from the largest to the smallest
+    let mut last_power = u32::MAX;
+
+    while index < words.len() {
+        let group = parse_hundreds(words, &mut index);
+
+        match words.get(index).and_then(|word| power(word)) {
+            Some(power) if power >= 3 && power < last_power => {
+                // "ming" alone is one thousand
+                let group = group.unwrap_or(1).checked_mul(10_i64.checked_pow(power)?)?;
+                total = total.checked_add(group)?;
+                last_power = power;
+                index += 1;
+            }
+            _ => {
+                total = total.checked_add(group?)?;
+                return (index == words.len()).then_some(total);
+            }
+        }
+    }
+
+    (index > 0).then_some(total)
+}
+
+// returns the number in the "12.5" form to keep every digit of the fraction
+pub(super) fn parse_float(words: &[String]) -> Option<String> {
+    let (negative, words) = split_sign(words);
+    let point = words.iter().position(|word| word == "butun")?;
+    let integer = parse_unsigned(&words[..point])?;
+    let rest = &words[point + 1..];
+
+    // the longest one, "o'n mingdan" rather than "mingdan"
+    let (digits, prefix) = FRACTION_PREFIXES
+        .iter()
+        .filter(|(_, prefix)| rest.starts_with(prefix))
+        .max_by_key(|(_, prefix)| prefix.len())?;
+
+    let fraction = parse_unsigned(&rest[prefix.len()..])?;
+    if 10_i64
+        .checked_pow(*digits as u32)
+        .is_some_and(|limit| fraction >= limit)
+    {
+        return None;
+    }
+
+    let sign = if negative { "-" } else { "" };
+    Some(format!("{sign}{integer}.{fraction:0digits$}"))
+}
+
+// the longest number written in words starting at tokens[start], along with the index of the token after it
+pub(super) fn number_at(tokens: &[Token], start: usize) -> Option<(String, usize)> {
+    // indices of the tokens and their normalized words
+    let mut indices: Vec<usize> = vec![];
+    let mut words: Vec<String> = vec![];
+
+    let mut index = start;
+    while let Some(token) = tokens.get(index) {
+        if token.kind != TokenKind::Word {
+            break;
+        }
+
+        let word = normalize_word(&token.text);
+        if !is_number_word(&word) {
+            break;
+        }
+
+        indices.push(index);
+        words.push(word);
+
+        match tokens.get(index + 1) {
+            Some(space) if space.kind == TokenKind::Whitespace && !space.text.contains('\n') => {
+                index += 2;
+            }
+            _ => break,
+        }
+    }
+
+    (1..=words.len()).rev().find_map(|length| {
+        let words = &words[..length];
+        // yuz (face) and ming alone are more likely to be ordinary words
+        if length == 1 && value(&words[0]).is_none() {
+            return None;
+        }
+        if length == 1 && words[0] == "bir" && is_article(tokens, indices[0]) {
+            return None;
+        }
+
+        let number = parse_float(words).or_else(|| parse_integer(words).map(|n| n.to_string()));
+
+        number.map(|number| (number, indices[length - 1] + 1))
+    })
+}
+
+// whether "bir" at tokens[index] is an article, followed by a word like kuni or oz
+fn is_article(tokens: &[Token], index: usize) -> bool {
+    match (tokens.get(index + 1), tokens.get(index + 2)) {
+        (Some(space), Some(next))
+            if space.kind == TokenKind::Whitespace && next.kind == TokenKind::Word =>
+        {
+            prelude::BIR_ARTICLE.contains(&normalize_word(&next.text).as_str())
+        }
+        _ => false,
+    }
 }
 
 #[cfg(test)]
 mod as_tests {
     use super::*;
 
+    // protected spans of the text in brackets
+    fn protect(text: &str, patterns: &[&Regex]) -> String {
+        let mut result = String::new();
+        let mut last = 0;
+
+        for span in protected_spans(text, patterns) {
+            result.push_str(&text[last..span.start]);
+            result.push_str(&format!("〈{}〉", &text[span.clone()]));
+            last = span.end;
+        }
+        result.push_str(&text[last..]);
+
+        result
+    }
+
+    // the same with patterns compiled on the spot
+    fn protect_patterns(text: &str, patterns: &[&str]) -> String {
+        let regexes: Vec<Regex> = patterns
+            .iter()
+            .map(|pattern| Regex::new(pattern).unwrap())
+            .collect();
+
+        protect(text, &regexes.iter().collect::<Vec<&Regex>>())
+    }
+
     #[test]
-    fn wrap_ips_test() {
+    fn ips_test() {
         let input =
             "12 124.34.5.234 12.3 2001:db8:3c4d:0015:0000:0000:1a2f:1a2b hello 2001:db8:3c4d:15::";
         let expected = "12 〈124.34.5.234〉 12.3 〈2001:db8:3c4d:0015:0000:0000:1a2f:1a2b〉 hello 〈2001:db8:3c4d:15::〉";
 
-        assert_eq!(wrap_ips(input), expected.to_string());
+        assert_eq!(protect(input, &[&*IPV4, &*IPV6]), expected.to_string());
+        assert_eq!(
+            protect("2001:db8::1, ::1", &[&*IPV6]),
+            "〈2001:db8::1〉, 〈::1〉"
+        );
     }
 
     #[test]
-    fn wrap_phones_test() {
+    fn phones_test() {
         let input = "salom +998936523602 325 12.3 daraxt 998712345689 71234 336519087";
         let expected = "salom +〈998936523602〉 325 12.3 daraxt 〈998712345689〉 71234 336519087";
 
-        assert_eq!(wrap_phones(input), expected.to_string());
+        assert_eq!(protect(input, &[&*PHONE]), expected.to_string());
+
+        let input = "(90) 123-45-67, 90 123 45 67, 998 (71) 234 56 78, 12 345 67";
+        let expected = "〈(90) 123-45-67〉, 〈90 123 45 67〉, 〈998 (71) 234 56 78〉, 12 345 67";
+
+        assert_eq!(protect(input, &[&*PHONE]), expected.to_string());
+    }
+
+    #[test]
+    fn protected_spans_test() {
+        // every match once, in order, overlapping ones merged
+        assert_eq!(
+            protect_patterns("1 12 1 5", &["12 1", r"(?<![0-9])1(?![0-9])", "1 5"]),
+            "〈1〉 〈12 1 5〉"
+        );
+        assert_eq!(protect_patterns("12 12", &["12"]), "〈12〉 〈12〉");
+        assert_eq!(protect("", &[&*PHONE]), "");
+    }
+
+    #[test]
+    fn entities_test() {
+        assert_eq!(
+            protect("AA1234567, AB 1234567, XAC7654321, AD12345", &[&*PASSPORT]),
+            "〈AA1234567〉, 〈AB 1234567〉, XAC7654321, AD12345"
+        );
+        assert_eq!(
+            protect(
+                "INN: 301234567, PINFL 31234567890123, 31234567890123, 123456789",
+                &[&*TAX_ID, &*PERSONAL_ID]
+            ),
+            "〈INN: 301234567〉, 〈PINFL 31234567890123〉, 31234567890123, 123456789"
+        );
+        assert_eq!(
+            protect(
+                "8600 1234 5678 9012, 9860-1234-5678-9012, 8600 1234-5678 9012, 8600123456789012",
+                &[&*CARD]
+            ),
+            "〈8600 1234 5678 9012〉, 〈9860-1234-5678-9012〉, 8600 1234-5678 9012, 8600123456789012"
+        );
+        assert_eq!(
+            protect("100011, 1000222, 300000, 100033.5", &[&*POSTAL_CODE]),
+            "〈100011〉, 1000222, 300000, 100033.5"
+        );
+        assert_eq!(
+            protect("v2, v1.2, 1.2.3, 2.0.1-beta, 13.01.2022, 1.5", &[&*VERSION]),
+            "〈v2〉, 〈v1.2〉, 〈1.2.3〉, 〈2.0.1-beta〉, 13.01.2022, 1.5"
+        );
+        assert_eq!(
+            protect(
+                "13.01.2022, 13/01/2022, 2022-01-13, 13.01/2022, 1.2.3",
+                &[&*DATE]
+            ),
+            "〈13.01.2022〉, 〈13/01/2022〉, 〈2022-01-13〉, 13.01/2022, 1.2.3"
+        );
+    }
+
+    fn convert_floats(text: &str, alphabet: Alphabet) -> Result<String, KorrektorError> {
+        convert(text, &[floats(alphabet)])
+    }
+
+    fn convert_integers(text: &str, alphabet: Alphabet) -> Result<String, KorrektorError> {
+        convert(text, &[integers(alphabet)])
+    }
+
+    fn convert_ordinals(text: &str, alphabet: Alphabet) -> Result<String, KorrektorError> {
+        convert(text, &[ordinals(alphabet)])
     }
 
     #[test]
     fn convert_floats_test() {
         assert_eq!(
-            convert_floats("12 12.5 13.1 5").unwrap(),
+            convert_floats("12 12.5 13.1 5", Alphabet::Latin).unwrap(),
             "12 o‘n ikki butun o‘ndan besh o‘n uch butun o‘ndan bir 5".to_string()
         );
+        assert_eq!(
+            convert_floats("1.5 11.5", Alphabet::Latin).unwrap(),
+            "bir butun o‘ndan besh o‘n bir butun o‘ndan besh".to_string()
+        );
+    }
+
+    #[test]
+    fn convert_integers_test() {
+        assert_eq!(
+            convert_integers("1 12, 2, 22, 122", Alphabet::Latin).unwrap(),
+            "bir o‘n ikki, ikki, yigirma ikki, bir yuz yigirma ikki".to_string()
+        );
+    }
+
+    #[test]
+    fn convert_ordinals_test() {
+        assert_eq!(
+            convert_ordinals("3-chi, 3-nchi 3-chilonzor 3-4 3-", Alphabet::Latin).unwrap(),
+            "uchinchi, uchinchi uchinchi chilonzor 3-4 3-".to_string()
+        );
+        assert_eq!(
+            convert_ordinals("3-чи 10-синф", Alphabet::Cyrillic).unwrap(),
+            "учинчи ўнинчи синф".to_string()
+        );
+    }
+
+    #[test]
+    fn convert_test() {
+        // the longest match wins over the earlier pattern
+        assert_eq!(
+            convert(
+                "1.5 va 7",
+                &[integers(Alphabet::Latin), floats(Alphabet::Latin)]
+            )
+            .unwrap(),
+            "bir butun o‘ndan besh va yetti".to_string()
+        );
+        // the replaced words are not matched again
+        assert_eq!(
+            convert(
+                "3-4-sinf",
+                &[ordinals(Alphabet::Latin), integers(Alphabet::Latin)]
+            )
+            .unwrap(),
+            "uch-to‘rtinchi sinf".to_string()
+        );
+    }
+
+    #[test]
+    fn parse_integer_test() {
+        let parse = |text: &str| {
+            let words: Vec<String> = text.split(' ').map(normalize_word).collect();
+            parse_integer(&words)
+        };
+
+        assert_eq!(parse("nol"), Some(0));
+        assert_eq!(parse("yuz"), Some(100));
+        assert_eq!(parse("ming to'qqiz yuz to'qson"), Some(1990));
+        assert_eq!(parse("ikki million bir ming"), Some(2_001_000));
+        assert_eq!(parse("ikki uch"), None);
+        assert_eq!(parse("ming million"), None);
+        assert_eq!(parse("nol bir"), None);
+        assert_eq!(parse("minus o'n besh"), Some(-15));
+        assert_eq!(parse("bir kvintillion"), Some(1_000_000_000_000_000_000));
+        assert_eq!(parse("o'n kvintillion"), None);
     }
 }
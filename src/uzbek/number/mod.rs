@@ -1,201 +1,660 @@
-//! Functions to return Uzbek word equivalent of numbers.
+//! Functions to return Uzbek word equivalent of numbers and back.
 //!
-//! Only latin mode supported currently.
+//! Numbers are written in words in either of the alphabets,
+//! words are read back from both of them.
 use crate::error::KorrektorError;
-use fancy_regex;
+use crate::uzbek::{detect_script, tokenize, transliterator, Alphabet};
+use once_cell::sync::Lazy;
 
+mod entities;
 mod helper;
 mod prelude;
+mod roman;
+
+pub use roman::{integer_to_roman, roman_to_integer};
+
+/// Names of the powers of a thousand used to name large numbers.
+///
+/// The first name stands for 10³, the second one for 10⁶ and so on. Names are
+/// written in latin, cyrillic words are transliterated from them. The default
+/// names go up to `detsillion` (10³³); [`KorrektorError::NumberOverflow`] is
+/// returned for numbers that need a name beyond the last one.
+///
+/// # Example
+/// ```rust
+///use korrektor::uzbek::number::{self, ScaleNames};
+///use korrektor::uzbek::Alphabet;
+///
+/// let scales = ScaleNames::new(&["ming", "million", "milliard", "trillion"]);
+/// let output = number::integer_to_word_with("2000000000000", Alphabet::Latin, &scales).unwrap();
+/// assert_eq!(output, "ikki trillion".to_string());
+///
+/// assert!(number::integer_to_word_with("2000000000000000", Alphabet::Latin, &scales).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScaleNames {
+    names: Vec<String>,
+}
+
+impl ScaleNames {
+    /// Creates scale names starting from the name of a thousand.
+    pub fn new(names: &[&str]) -> Self {
+        ScaleNames {
+            names: names.iter().map(|name| name.to_string()).collect(),
+        }
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+}
+
+impl Default for ScaleNames {
+    fn default() -> Self {
+        ScaleNames::new(prelude::SCALES)
+    }
+}
+
+static SCALES: Lazy<ScaleNames> = Lazy::new(ScaleNames::default);
 
 /// Returns a word representation of a given integer number.
 ///
-/// Given an integer string slice returns a String with corresponding word equivalent.
+/// Given an integer string slice returns a String with corresponding word equivalent
+/// written in the given alphabet. The integer may have any number of digits,
+/// leading zeros, a `-` or `+` sign and thousands separators (`1 000 000` or `1,000,000`).
 ///
-/// # Panics
-/// - if a string is not a valid integer (make sure you remove any other symbols and whitespace)
-/// - if it has more than 18 digits in it (i.e. "999999999999999999" is still valid)
+/// # Errors
+/// - [`KorrektorError::InvalidNumber`] if a string is not a valid integer (make sure you remove any other symbols and whitespace)
+/// - [`KorrektorError::NumberOverflow`] if it is too large to be named with [`ScaleNames::default`] (more than 36 digits)
 ///
 /// # Example
 /// ```rust
-///use korrektor::uzbek::number;
+///use korrektor::uzbek::{number, Alphabet};
 ///
-/// let output = number::integer_to_word("1024").unwrap();
+/// let output = number::integer_to_word("1024", Alphabet::Latin).unwrap();
 /// let expected = "bir ming yigirma to‘rt".to_string();
 /// assert_eq!(output, expected);
+///
+/// let output = number::integer_to_word("1024", Alphabet::Cyrillic).unwrap();
+/// let expected = "бир минг йигирма тўрт".to_string();
+/// assert_eq!(output, expected);
+///
+/// let output = number::integer_to_word("-1 000 005", Alphabet::Latin).unwrap();
+/// let expected = "minus bir million besh".to_string();
+/// assert_eq!(output, expected);
 /// ```
-pub fn integer_to_word(number: &str) -> Result<String, KorrektorError> {
-    if !helper::is_valid_integer(number) {
-        return Err(KorrektorError::InvalidNumber(
-            number.to_string(),
-            "Not a valid integer".to_string(),
-        ));
-    }
-    if number.len() > 18 {
-        return Err(KorrektorError::InvalidNumber(
-            number.to_string(),
-            "Integer should not contain more than 18 digits".to_string(),
-        ));
-    }
+pub fn integer_to_word(number: &str, alphabet: Alphabet) -> Result<String, KorrektorError> {
+    integer_to_word_with(number, alphabet, &SCALES)
+}
 
-    let number: i64 = number.parse().unwrap();
+/// Returns a word representation of a given integer number, naming
+/// large numbers with the given scale names.
+///
+/// See [`integer_to_word`] and [`ScaleNames`].
+pub fn integer_to_word_with(
+    number: &str,
+    alphabet: Alphabet,
+    scales: &ScaleNames,
+) -> Result<String, KorrektorError> {
+    Ok(in_alphabet(integer_words(number, scales)?, alphabet))
+}
 
-    match number {
-        0 => Ok(String::from("nol")),
-        1..=19 => {
-            let index = (number - 1) as usize;
-            Ok(prelude::NUM_1_TO_19[index].1.to_string())
-        }
-        20..=99 => {
-            let index: usize = (number / 10 - 2) as usize;
-            Ok(prelude::TEEN[index].1.to_string()
-                + " "
-                + &integer_to_word(&(number % 10).to_string())?)
-        }
-        100..=999 => one(number, 2),
-        _ => {
-            let mut i = 4;
-            while i < 27 {
-                if number < i64::pow(10, i) {
-                    break;
-                }
-                i += 1;
-            }
+// latin words of an integer
+fn integer_words(number: &str, scales: &ScaleNames) -> Result<String, KorrektorError> {
+    let (sign, digits) = helper::split_integer(number).ok_or_else(|| {
+        KorrektorError::InvalidNumber(number.to_string(), "Not a valid integer".to_string())
+    })?;
+    let words = digits_words(&digits, scales).ok_or_else(|| overflow(number))?;
 
-            if i % 3 != 0 {
-                Ok(hundred(number, i - (i % 3))?)
-            } else {
-                Ok(one(number, i - 3)?)
-            }
-        }
+    // no "minus nol"
+    match sign {
+        Some(sign) if digits != "0" => Ok(format!("{sign} {words}")),
+        _ => Ok(words),
+    }
+}
+
+// latin words of an integer without a sign, ordinal, collective and distributive
+// numerals are not formed from signed numbers
+fn unsigned_words(number: &str) -> Result<String, KorrektorError> {
+    match helper::split_integer(number) {
+        Some((Some(_), _)) => Err(KorrektorError::InvalidNumber(
+            number.to_string(),
+            "Not an unsigned integer".to_string(),
+        )),
+        _ => integer_words(number, &SCALES),
     }
 }
 
 /// Returns a word representation of a given floating-point number.
 ///
-/// Given an floating-point number string slice returns a String with corresponding word equivalent.
+/// Given an floating-point number string slice returns a String with corresponding word equivalent
+/// written in the given alphabet. Its integer part is written like in [`integer_to_word`],
+/// the fraction may have any number of digits.
 ///
-/// # Panics
-/// - if a string is not a valid floating-point number (exclude integers or use x.0 form, make sure you remove any other symbols and whitespace)
-/// - if it has more than 18 digits in its integer or precision part (i.e. "999999999999999999.999999999999999999" is still valid)
+/// # Errors
+/// - [`KorrektorError::InvalidNumber`] if a string is not a valid floating-point number (exclude integers or use x.0 form, make sure you remove any other symbols and whitespace)
+/// - [`KorrektorError::NumberOverflow`] if its integer part or precision is too large to be named with [`ScaleNames::default`]
 ///
 /// # Example
 /// ```rust
-///use korrektor::uzbek::number;
+///use korrektor::uzbek::{number, Alphabet};
 ///
-/// let output = number::float_to_word("574.789").unwrap();
+/// let output = number::float_to_word("574.789", Alphabet::Latin).unwrap();
 /// let expected = "besh yuz yetmish to‘rt butun mingdan yetti yuz sakson to‘qqiz".to_string();
 /// assert_eq!(output, expected);
+///
+/// let output = number::float_to_word("12.5", Alphabet::Cyrillic).unwrap();
+/// let expected = "ўн икки бутун ўндан беш".to_string();
+/// assert_eq!(output, expected);
 /// ```
-pub fn float_to_word(number: &str) -> Result<String, KorrektorError> {
-    if !helper::is_valid_float(number) {
-        return Err(KorrektorError::InvalidNumber(
+pub fn float_to_word(number: &str, alphabet: Alphabet) -> Result<String, KorrektorError> {
+    float_to_word_with(number, alphabet, &SCALES)
+}
+
+/// Returns a word representation of a given floating-point number, naming
+/// large numbers and precisions with the given scale names.
+///
+/// See [`float_to_word`] and [`ScaleNames`].
+pub fn float_to_word_with(
+    number: &str,
+    alphabet: Alphabet,
+    scales: &ScaleNames,
+) -> Result<String, KorrektorError> {
+    let invalid = || {
+        KorrektorError::InvalidNumber(
             number.to_string(),
             "Not a valid floating-point number".to_string(),
-        ));
+        )
+    };
+
+    let (integer, fraction) = number.rsplit_once('.').ok_or_else(invalid)?;
+    let (sign, integer) = helper::split_integer(integer).ok_or_else(invalid)?;
+    if fraction.is_empty() || !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(invalid());
     }
 
-    let number: Vec<&str> = number.split('.').collect();
+    let fraction_digits = helper::trim_zeros(fraction);
+    let zero = integer == "0" && fraction_digits == "0";
+
+    let integer = digits_words(&integer, scales).ok_or_else(|| overflow(number))?;
+    let prefix = fraction_prefix(fraction.len(), scales).ok_or_else(|| overflow(number))?;
+    let fraction = digits_words(fraction_digits, scales).ok_or_else(|| overflow(number))?;
+
+    // no "minus nol", the same as of integers
+    let words = format!("{integer} butun {prefix} {fraction}");
+    let words = match sign {
+        Some(sign) if !zero => format!("{sign} {words}"),
+        _ => words,
+    };
+
+    Ok(in_alphabet(words, alphabet))
+}
+
+/// Form of a collective numeral, see [`to_collective`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectiveForm {
+    /// `ikkov`, `uchov`, `beshov`.
+    Ov,
+    /// `ikkala`, `uchala`, `beshala`.
+    Ala,
+}
+
+/// Returns an ordinal numeral of a given integer number.
+///
+/// Given an integer string slice returns a String with corresponding ordinal
+/// word equivalent (birinchi, ikkinchi, o‘n uchinchi) written in the given alphabet.
+///
+/// # Errors
+/// The same as of [`integer_to_word`], and [`KorrektorError::InvalidNumber`]
+/// if a number has a sign (`-5`, `+5`).
+///
+/// # Example
+/// ```rust
+///use korrektor::uzbek::{number, Alphabet};
+///
+/// let output = number::to_ordinal("52", Alphabet::Latin).unwrap();
+/// let expected = "ellik ikkinchi".to_string();
+/// assert_eq!(output, expected);
+///
+/// let output = number::to_ordinal("50", Alphabet::Cyrillic).unwrap();
+/// let expected = "эллигинчи".to_string();
+/// assert_eq!(output, expected);
+/// ```
+pub fn to_ordinal(number: &str, alphabet: Alphabet) -> Result<String, KorrektorError> {
+    let words = unsigned_words(number)?;
+
+    // ellik -> elliginchi
+    let words = match words.strip_suffix('k') {
+        Some(stem) => stem.to_string() + "ginchi",
+        None if words.ends_with(['a', 'e', 'i', 'o', 'u']) => words + "nchi",
+        None => words + "inchi",
+    };
 
-    let fraction = number[1];
-    if number[1].len() > 18 {
+    Ok(in_alphabet(words, alphabet))
+}
+
+/// Returns a collective numeral of a given integer number.
+///
+/// Given an integer string slice from 2 to 10 returns a String with corresponding
+/// collective word equivalent in the given form and alphabet. Possessive suffixes
+/// are left to the caller (`ikkov` + `i` = `ikkovi`, `uchala` + `si` = `uchalasi`).
+///
+/// # Errors
+/// Returns [`KorrektorError::InvalidNumber`] if a string is not a valid unsigned
+/// integer or it is less than 2 or greater than 10.
+///
+/// # Example
+/// ```rust
+///use korrektor::uzbek::number::{self, CollectiveForm};
+///use korrektor::uzbek::Alphabet;
+///
+/// let output = number::to_collective("2", CollectiveForm::Ov, Alphabet::Latin).unwrap();
+/// assert_eq!(output, "ikkov".to_string());
+///
+/// let output = number::to_collective("3", CollectiveForm::Ala, Alphabet::Cyrillic).unwrap();
+/// assert_eq!(output, "учала".to_string());
+/// ```
+pub fn to_collective(
+    number: &str,
+    form: CollectiveForm,
+    alphabet: Alphabet,
+) -> Result<String, KorrektorError> {
+    let words = unsigned_words(number)?;
+
+    if !(2..=10).contains(&word_to_integer(&words).unwrap_or_default()) {
         return Err(KorrektorError::InvalidNumber(
-            number[1].to_string(),
-            "Precision part should not contain more than 18 digits".to_string(),
+            number.to_string(),
+            "Collective numerals are formed from 2 to 10 only".to_string(),
         ));
     }
 
-    let fraction_prefix = get_fraction_prefix(fraction);
+    // ikki -> ikkov, olti -> oltala
+    let stem = words.strip_suffix('i').unwrap_or(&words);
+    let suffix = match form {
+        CollectiveForm::Ov => "ov",
+        CollectiveForm::Ala => "ala",
+    };
+
+    Ok(in_alphabet(stem.to_string() + suffix, alphabet))
+}
 
-    let integer = integer_to_word(number[0])?;
-    let fraction = integer_to_word(number[1])?;
+/// Returns a distributive numeral of a given integer number.
+///
+/// Given an integer string slice returns a String with corresponding distributive
+/// word equivalent (birtadan, o‘n ikkitadan) written in the given alphabet.
+///
+/// # Errors
+/// The same as of [`integer_to_word`], and [`KorrektorError::InvalidNumber`]
+/// if a number has a sign (`-5`, `+5`).
+///
+/// # Example
+/// ```rust
+///use korrektor::uzbek::{number, Alphabet};
+///
+/// let output = number::to_distributive("12", Alphabet::Latin).unwrap();
+/// let expected = "o‘n ikkitadan".to_string();
+/// assert_eq!(output, expected);
+/// ```
+pub fn to_distributive(number: &str, alphabet: Alphabet) -> Result<String, KorrektorError> {
+    Ok(in_alphabet(unsigned_words(number)? + "tadan", alphabet))
+}
 
-    let fraction = fraction_prefix + " " + &fraction;
+/// Settings of [`numbers_to_word_with`].
+///
+/// The `protect_*` options leave numbers of the given kind as they are.
+#[derive(Debug, Clone)]
+pub struct NumberOptions {
+    /// Alphabet of the words, the one most of the text is written in if `None`.
+    pub alphabet: Option<Alphabet>,
+    /// Read Roman numerals followed by words like asr, bob, qism or jild
+    /// as ordinals (`XXI asr` is `yigirma birinchi asr`), `false` by default.
+    pub roman_numerals: bool,
+    /// IPv4 and IPv6 addresses, `true` by default.
+    pub protect_ips: bool,
+    /// Phone numbers in the full (`998901234567`) and in the local
+    /// (`(90) 123-45-67`, `90 123 45 67`) form, `true` by default.
+    pub protect_phones: bool,
+    /// Passport numbers (`AA1234567`), `true` by default.
+    pub protect_passports: bool,
+    /// Taxpayer numbers after INN or STIR (`INN 123456789`) and personal
+    /// numbers of 14 digits after PINFL or JSHSHIR (`PINFL 31234567890123`),
+    /// `true` by default.
+    pub protect_ids: bool,
+    /// Card numbers of 16 digits in groups of four (`8600 1234 5678 9012`),
+    /// `true` by default.
+    pub protect_cards: bool,
+    /// Six-digit postal codes (`100011`), `false` by default
+    /// as they can not be told from other numbers.
+    pub protect_postal_codes: bool,
+    /// Versions (`v1.2`, `1.2.3`), `true` by default.
+    pub protect_versions: bool,
+    /// Dates (`13.01.2022`, `13/01/2022`, `2022-01-13`), `false` by default,
+    /// which reads `13.01.2022` as a date.
+    pub protect_dates: bool,
+}
 
-    Ok(integer + " butun " + &fraction)
+impl Default for NumberOptions {
+    fn default() -> Self {
+        NumberOptions {
+            alphabet: None,
+            roman_numerals: false,
+            protect_ips: true,
+            protect_phones: true,
+            protect_passports: true,
+            protect_ids: true,
+            protect_cards: true,
+            protect_postal_codes: false,
+            protect_versions: true,
+            protect_dates: false,
+        }
+    }
 }
 
 /// Converts all numbers in text into their word representation.
 ///
 /// Given a string slice returns a String with all numbers
-/// converted into their word equivalent
-/// (excluding IP addresses and Uzbekistan phone numbers in their full form)
+/// converted into their word equivalent, leaving IP addresses, phone, passport,
+/// card and ID numbers and versions as they are (see [`NumberOptions`]).
+/// Numbers followed by a hyphen are ordinals (`5-sinf`, `3-chi`, `12-yanvar`).
+/// Thousands separated by plain spaces are one number from two groups on
+/// (`1 000 000`), `3 100 ta` is read as two numbers.
+/// Money (`$12.50`, `5 000 so‘m`), percentages (`15%`), time (`12:30`) and
+/// dates (`13.01.2022`, `2022 yil 13 yanvar`) are read the way they are spoken.
+/// Words are written in the given alphabet, or in the one most of the text
+/// is written in if it is `None` (latin for a text with no Uzbek letters).
 ///
 /// # Example
 /// ```rust
 /// use korrektor::uzbek::number;
 ///
-/// let output = number::numbers_to_word("12, salom 998336523409 12.5 daraxt 1024 124.34.5.234").unwrap();
+/// let output = number::numbers_to_word("12, salom 998336523409 12.5 daraxt 1024 124.34.5.234", None).unwrap();
 /// let expected = "o‘n ikki, salom 998336523409 o‘n ikki butun o‘ndan besh daraxt bir ming yigirma to‘rt 124.34.5.234".to_string();
 /// assert_eq!(output, expected);
+///
+/// let output = number::numbers_to_word("Ҳужжат 2023 йилда 5-синфда", None).unwrap();
+/// let expected = "Ҳужжат икки минг йигирма уч йилда бешинчи синфда".to_string();
+/// assert_eq!(output, expected);
 /// ```
-pub fn numbers_to_word(text: &str) -> Result<String, KorrektorError> {
-    let mut input = helper::wrap_ips(text);
-    input = helper::wrap_phones(&input);
+pub fn numbers_to_word(text: &str, alphabet: Option<Alphabet>) -> Result<String, KorrektorError> {
+    numbers_to_word_with(
+        text,
+        &NumberOptions {
+            alphabet,
+            ..NumberOptions::default()
+        },
+    )
+}
 
-    // each capture is a part of text outside special brackets (may have multiple words and/or numbers)
-    let re = fancy_regex::Regex::new("([^〈〉](?![^〈]*〉))+").unwrap();
-    for capture in re.captures_iter(&input.clone()) {
-        let initial_cap = capture.unwrap()[0].to_string();
-        let mut capture = initial_cap.clone();
+/// Converts all numbers in text into their word representation with the given options.
+///
+/// See [`numbers_to_word`] and [`NumberOptions`].
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::number::{self, NumberOptions};
+///
+/// let options = NumberOptions {
+///     roman_numerals: true,
+///     ..NumberOptions::default()
+/// };
+/// let output = number::numbers_to_word_with("XXI asr, I va V", &options).unwrap();
+/// assert_eq!(output, "yigirma birinchi asr, I va V".to_string());
+/// ```
+pub fn numbers_to_word_with(text: &str, options: &NumberOptions) -> Result<String, KorrektorError> {
+    let alphabet = options
+        .alphabet
+        .or(detect_script(text).dominant)
+        .unwrap_or(Alphabet::Latin);
 
-        capture = capture.replace(&capture, &helper::convert_floats(&capture)?);
-        input = input.replacen(&initial_cap, &helper::convert_integers(&capture)?, 1);
+    let mut patterns = vec![];
+    for (enabled, protected) in [
+        (options.protect_ips, &[&*helper::IPV4, &*helper::IPV6][..]),
+        (options.protect_phones, &[&*helper::PHONE]),
+        (options.protect_passports, &[&*helper::PASSPORT]),
+        (options.protect_ids, &[&*helper::TAX_ID, &*helper::PERSONAL_ID]),
+        (options.protect_cards, &[&*helper::CARD]),
+        (options.protect_postal_codes, &[&*helper::POSTAL_CODE]),
+        (options.protect_versions, &[&*helper::VERSION]),
+        (options.protect_dates, &[&*helper::DATE]),
+    ] {
+        if enabled {
+            patterns.extend_from_slice(protected);
+        }
     }
 
-    let re = regex::Regex::new("[〈〉]").unwrap();
-    input = re.replace_all(&input, "").to_string();
+    // a single pass over the text: parts between the protected spans are converted,
+    // the spans themselves are copied as they are
+    let mut result = String::with_capacity(text.len());
+    let mut last = 0;
+    let spans = helper::protected_spans(text, &patterns);
+    for span in spans
+        .into_iter()
+        .chain(std::iter::once(text.len()..text.len()))
+    {
+        result.push_str(&convert_numbers(
+            &text[last..span.start],
+            options,
+            alphabet,
+        )?);
+        result.push_str(&text[span.clone()]);
+        last = span.end;
+    }
 
-    Ok(input)
+    Ok(result)
 }
 
-fn base(number: i64, power: u32) -> Result<String, KorrektorError> {
-    let base = integer_to_word(&(number / i64::pow(10, power)).to_string())?;
-    let mult_tuple = prelude::MULT.iter().find(|x| x.0 == power as i32);
-    let mult = match mult_tuple {
-        Some(tuple) => tuple.1,
-        None => panic!("Such multiplication value is not found! power is {power}"),
-    };
+// converts every number of the part of text that has nothing protected in it
+fn convert_numbers(
+    text: &str,
+    options: &NumberOptions,
+    alphabet: Alphabet,
+) -> Result<String, KorrektorError> {
+    let mut conversions = vec![];
+    if options.roman_numerals {
+        conversions.push(roman::romans(alphabet));
+    }
+    conversions.extend(entities::conversions(alphabet));
+    conversions.push(helper::ordinals(alphabet));
+    conversions.push(helper::floats(alphabet));
+    conversions.push(helper::integers(alphabet));
 
-    Ok(base + " " + mult)
+    // every kind of number is matched against the part as it is, in a single pass
+    helper::convert(text, &conversions)
 }
 
-fn one(number: i64, power: u32) -> Result<String, KorrektorError> {
-    let y = number % i64::pow(10, power);
-    let s = integer_to_word(&y.to_string())?;
-    let separator = if power == 2 && !s.is_empty() {
-        " "
-    } else if y < 100 {
-        if y == 0 {
-            ""
-        } else {
-            " "
+/// Returns an integer written in words.
+///
+/// Accepts words written in latin or cyrillic, in any case and with any apostrophe.
+/// "ming" alone stands for one thousand, like "yuz" for one hundred.
+///
+/// # Errors
+/// Returns [`KorrektorError::InvalidNumber`] if the text is not a number
+/// written in words or it does not fit into [`i64`].
+///
+/// # Example
+/// ```rust
+///use korrektor::uzbek::number;
+///
+/// assert_eq!(number::word_to_integer("bir ming yigirma to‘rt").unwrap(), 1024);
+/// assert_eq!(number::word_to_integer("Ming to'qqiz yuz to`qson").unwrap(), 1990);
+/// assert_eq!(number::word_to_integer("бир минг йигирма тўрт").unwrap(), 1024);
+/// assert!(number::word_to_integer("ikki uch").is_err());
+/// ```
+pub fn word_to_integer(text: &str) -> Result<i64, KorrektorError> {
+    let words: Vec<String> = text
+        .split_whitespace()
+        .map(helper::normalize_word)
+        .collect();
+
+    helper::parse_integer(&words).ok_or_else(|| {
+        KorrektorError::InvalidNumber(
+            text.to_string(),
+            "Not a valid integer written in words".to_string(),
+        )
+    })
+}
+
+/// Returns a floating-point number written in words.
+///
+/// Accepts the form returned by [`float_to_word`], written in latin or cyrillic,
+/// in any case and with any apostrophe.
+///
+/// # Errors
+/// Returns [`KorrektorError::InvalidNumber`] if the text is not a floating-point
+/// number written in words or the fraction has more digits than its prefix allows
+/// (i.e. "bir butun o‘ndan o‘n").
+///
+/// # Example
+/// ```rust
+///use korrektor::uzbek::number;
+///
+/// assert_eq!(number::word_to_float("o‘n ikki butun o‘ndan besh").unwrap(), 12.5);
+/// assert_eq!(number::word_to_float("уч бутун юздан беш").unwrap(), 3.05);
+/// ```
+pub fn word_to_float(text: &str) -> Result<f64, KorrektorError> {
+    let words: Vec<String> = text
+        .split_whitespace()
+        .map(helper::normalize_word)
+        .collect();
+
+    match helper::parse_float(&words) {
+        Some(number) => Ok(number.parse().unwrap()),
+        None => Err(KorrektorError::InvalidNumber(
+            text.to_string(),
+            "Not a valid floating-point number written in words".to_string(),
+        )),
+    }
+}
+
+/// Converts all numbers written in words in text into digits.
+///
+/// Given a string slice returns a String with every number written in words
+/// (in the forms returned by [`integer_to_word`] and [`float_to_word`],
+/// latin or cyrillic) replaced with digits. Numbers do not continue
+/// through punctuation or line breaks, and "yuz" or "ming" alone are
+/// left as they are, since they are ordinary words as well. So is a lone
+/// "bir" used as an article (`bir kuni`, `bir oz`, `bir marta`).
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::number;
+///
+/// let output = number::words_to_numbers("o‘n ikki, salom o‘n ikki butun o‘ndan besh daraxt bir ming yigirma to‘rt");
+/// let expected = "12, salom 12.5 daraxt 1024".to_string();
+/// assert_eq!(output, expected);
+/// ```
+pub fn words_to_numbers(text: &str) -> String {
+    let tokens = tokenize::words(text);
+    let mut output = String::with_capacity(text.len());
+
+    let mut index = 0;
+    while index < tokens.len() {
+        match helper::number_at(&tokens, index) {
+            Some((number, next)) => {
+                output.push_str(&number);
+                index = next;
+            }
+            None => {
+                output.push_str(&tokens[index].text);
+                index += 1;
+            }
         }
-    } else {
-        " "
+    }
+
+    output
+}
+
+// words of digits with no leading zeros, None if there are not enough scale names
+fn digits_words(digits: &str, scales: &ScaleNames) -> Option<String> {
+    if digits == "0" {
+        return Some(String::from("nol"));
+    }
+
+    // groups of three digits, the first one may be shorter
+    let first = match digits.len() % 3 {
+        0 => 3,
+        length => length,
     };
+    let groups: Vec<&str> = std::iter::once(&digits[..first])
+        .chain(digits.as_bytes()[first..].chunks(3).map(|group| {
+            // digits are ascii
+            std::str::from_utf8(group).unwrap()
+        }))
+        .collect();
 
-    Ok(base(number, power)? + separator + &s)
+    if groups.len() - 1 > scales.names.len() {
+        return None;
+    }
+
+    let mut words: Vec<&str> = vec![];
+    for (index, group) in groups.iter().enumerate() {
+        let group: usize = group.parse().unwrap();
+        if group == 0 {
+            continue;
+        }
+
+        words.extend(hundreds(group));
+        let scale = groups.len() - 1 - index;
+        if scale > 0 {
+            words.push(&scales.names[scale - 1]);
+        }
+    }
+
+    Some(words.join(" "))
 }
 
-fn hundred(number: i64, power: u32) -> Result<String, KorrektorError> {
-    let y = number % i64::pow(10, power);
-    let sep = if y < 100 {
-        if y == 0 {
-            ""
-        } else {
-            " "
+// words of a number from 1 to 999
+fn hundreds(number: usize) -> Vec<&'static str> {
+    let mut words: Vec<&str> = vec![];
+
+    if number >= 100 {
+        words.push(prelude::NUM_1_TO_19[number / 100 - 1].1);
+        words.push("yuz");
+    }
+
+    match number % 100 {
+        0 => {}
+        rest @ 1..=19 => words.push(prelude::NUM_1_TO_19[rest - 1].1),
+        rest => {
+            words.push(prelude::TEEN[rest / 10 - 2].1);
+            if rest % 10 != 0 {
+                words.push(prelude::NUM_1_TO_19[rest % 10 - 1].1);
+            }
         }
-    } else {
-        " "
+    }
+
+    words
+}
+
+// o‘ndan, yuzdan, mingdan, o‘n mingdan and so on for the given number of digits
+fn fraction_prefix(digits: usize, scales: &ScaleNames) -> Option<String> {
+    let size = match digits % 3 {
+        1 => "o‘n",
+        2 => "yuz",
+        _ => "",
+    };
+
+    let prefix = match digits / 3 {
+        0 => size.to_string(),
+        scale if size.is_empty() => scales.names.get(scale - 1)?.clone(),
+        scale => format!("{size} {}", scales.names.get(scale - 1)?),
     };
 
-    Ok(String::new() + &base(number, power)? + sep + &integer_to_word(&y.to_string())?)
+    Some(prefix + "dan")
 }
 
-fn get_fraction_prefix(number: &str) -> String {
-    let prefix = prelude::FLOAT_PREFIX[number.len() - 1];
+fn overflow(number: &str) -> KorrektorError {
+    KorrektorError::NumberOverflow(
+        number.to_string(),
+        "Number is too large to be named".to_string(),
+    )
+}
 
-    prefix.to_string()
+fn in_alphabet(words: String, alphabet: Alphabet) -> String {
+    match alphabet {
+        Alphabet::Latin => words,
+        Alphabet::Cyrillic => transliterator::to_cyrillic(words),
+    }
 }
 
 #[cfg(test)]
@@ -203,58 +662,146 @@ mod as_tests {
     use super::*;
 
     #[test]
-    fn base_test() {
-        assert_eq!(base(532, 2).unwrap(), String::from("besh yuz"));
+    fn hundreds_test() {
+        assert_eq!(hundreds(500), vec!["besh", "yuz"]);
+        assert_eq!(hundreds(532), vec!["besh", "yuz", "o‘ttiz", "ikki"]);
+        assert_eq!(hundreds(11), vec!["o‘n bir"]);
     }
 
     #[test]
-    fn one_test() {
-        assert_eq!(one(532, 2).unwrap(), String::from("besh yuz o‘ttiz ikki"));
+    fn digits_words_test() {
+        assert_eq!(
+            digits_words("3456", &SCALES).unwrap(),
+            String::from("uch ming to‘rt yuz ellik olti")
+        );
+        assert_eq!(
+            digits_words("1000000000000000000", &SCALES).unwrap(),
+            String::from("bir kvintillion")
+        );
+        assert_eq!(
+            digits_words("12000000000000000000000000000000000", &SCALES).unwrap(),
+            String::from("o‘n ikki detsillion")
+        );
+        assert!(digits_words(&"9".repeat(37), &SCALES).is_none());
     }
 
     #[test]
-    fn hundred_test() {
+    fn signs_and_separators_test() {
+        let cases = [
+            ("-5", "minus besh"),
+            ("−5", "minus besh"),
+            ("+5", "plyus besh"),
+            ("-0", "nol"),
+            ("007", "yetti"),
+            ("000", "nol"),
+            ("1 000 000", "bir million"),
+            ("1,000,000", "bir million"),
+            ("12\u{a0}500", "o‘n ikki ming besh yuz"),
+            ("12 500", "o‘n ikki ming besh yuz"),
+        ];
+
+        for (number, expected) in cases {
+            assert_eq!(integer_to_word(number, Alphabet::Latin).unwrap(), expected);
+        }
+
+        for number in ["1 00", "1,000 000", "10,00", "--5", "5-", "", "+", "1.5"] {
+            match integer_to_word(number, Alphabet::Latin) {
+                Err(KorrektorError::InvalidNumber(..)) => {}
+                other => panic!("expected an error for {number:?}, got {other:?}"),
+            }
+        }
+
+        match integer_to_word(&"1".repeat(40), Alphabet::Latin) {
+            Err(KorrektorError::NumberOverflow(..)) => {}
+            other => panic!("expected an overflow, got {other:?}"),
+        }
+
         assert_eq!(
-            hundred(3456, 3).unwrap(),
-            String::from("uch ming to‘rt yuz ellik olti")
+            float_to_word("-0.25", Alphabet::Latin).unwrap(),
+            "minus nol butun yuzdan yigirma besh"
+        );
+        assert_eq!(
+            float_to_word("1,000.5", Alphabet::Latin).unwrap(),
+            "bir ming butun o‘ndan besh"
+        );
+        assert_eq!(
+            float_to_word("0.0000000000000000001", Alphabet::Latin).unwrap(),
+            "nol butun o‘n kvintilliondan bir"
+        );
+        assert_eq!(
+            float_to_word("-0.0", Alphabet::Latin).unwrap(),
+            "nol butun o‘ndan nol"
+        );
+        assert!(float_to_word("1.", Alphabet::Latin).is_err());
+        assert!(float_to_word("1.-5", Alphabet::Latin).is_err());
+
+        assert_eq!(
+            numbers_to_word("Harorat -5, narxi 5 000 so‘m, 1,500,000 va 5-7", None).unwrap(),
+            "Harorat minus besh, narxi besh ming so‘m, bir million besh yuz ming va besh-yetti"
+        );
+        // a single group after a space is another number, unless a unit follows
+        assert_eq!(
+            numbers_to_word("3 100 ta, 1 000 000 kishi, 12\u{a0}500 va 2 500 so‘m", None).unwrap(),
+            "uch bir yuz ta, bir million kishi, o‘n ikki ming besh yuz va ikki ming besh yuz so‘m"
         );
     }
 
     #[test]
     fn cw_test() {
-        assert_eq!(integer_to_word("0").unwrap(), String::from("nol"));
-        assert_eq!(integer_to_word("9").unwrap(), String::from("to‘qqiz"));
-        assert_eq!(integer_to_word("32").unwrap(), String::from("o‘ttiz ikki"));
         assert_eq!(
-            integer_to_word("104").unwrap(),
+            integer_to_word("0", Alphabet::Latin).unwrap(),
+            String::from("nol")
+        );
+        assert_eq!(
+            integer_to_word("9", Alphabet::Latin).unwrap(),
+            String::from("to‘qqiz")
+        );
+        assert_eq!(
+            integer_to_word("32", Alphabet::Latin).unwrap(),
+            String::from("o‘ttiz ikki")
+        );
+        assert_eq!(
+            integer_to_word("104", Alphabet::Latin).unwrap(),
             String::from("bir yuz to‘rt")
         );
         assert_eq!(
-            integer_to_word("1024").unwrap(),
+            integer_to_word("1024", Alphabet::Latin).unwrap(),
             String::from("bir ming yigirma to‘rt")
         );
         assert_eq!(
-            integer_to_word("3456").unwrap(),
+            integer_to_word("3456", Alphabet::Latin).unwrap(),
             String::from("uch ming to‘rt yuz ellik olti")
         );
+        assert_eq!(
+            integer_to_word("20", Alphabet::Latin).unwrap(),
+            String::from("yigirma")
+        );
+        assert_eq!(
+            integer_to_word("100", Alphabet::Latin).unwrap(),
+            String::from("bir yuz")
+        );
+        assert_eq!(
+            integer_to_word("1000000", Alphabet::Latin).unwrap(),
+            String::from("bir million")
+        );
     }
 
     #[test]
     fn float_test() {
         assert_eq!(
-            float_to_word("3.0").unwrap(),
+            float_to_word("3.0", Alphabet::Latin).unwrap(),
             String::from("uch butun o‘ndan nol")
         );
         assert_eq!(
-            float_to_word("3.75").unwrap(),
+            float_to_word("3.75", Alphabet::Latin).unwrap(),
             String::from("uch butun yuzdan yetmish besh")
         );
         assert_eq!(
-            float_to_word("3.754").unwrap(),
+            float_to_word("3.754", Alphabet::Latin).unwrap(),
             String::from("uch butun mingdan yetti yuz ellik to‘rt")
         );
         assert_eq!(
-            float_to_word("3.7548").unwrap(),
+            float_to_word("3.7548", Alphabet::Latin).unwrap(),
             String::from("uch butun o‘n mingdan yetti ming besh yuz qirq sakkiz")
         )
     }
@@ -264,6 +811,248 @@ mod as_tests {
         let input = "12, 998336523409 12.5 1024 124.34.5.234 2001:db8:3c4d:0015:0000:0000:1a2f:1a2b 12.5 1024";
         let expected = "o‘n ikki, 998336523409 o‘n ikki butun o‘ndan besh bir ming yigirma to‘rt 124.34.5.234 2001:db8:3c4d:0015:0000:0000:1a2f:1a2b o‘n ikki butun o‘ndan besh bir ming yigirma to‘rt";
 
-        assert_eq!(numbers_to_word(input).unwrap(), expected.to_string());
+        assert_eq!(numbers_to_word(input, None).unwrap(), expected.to_string());
+        assert_eq!(
+            numbers_to_word(input, Some(Alphabet::Latin)).unwrap(),
+            expected.to_string()
+        );
+    }
+
+    #[test]
+    fn cyrillic_test() {
+        assert_eq!(
+            integer_to_word("3456", Alphabet::Cyrillic).unwrap(),
+            String::from("уч минг тўрт юз эллик олти")
+        );
+        assert_eq!(
+            float_to_word("3.754", Alphabet::Cyrillic).unwrap(),
+            String::from("уч бутун мингдан етти юз эллик тўрт")
+        );
+
+        // the alphabet is detected from the text unless given
+        let input = "Тошкентда 12 та мактаб, 998336523409";
+        assert_eq!(
+            numbers_to_word(input, None).unwrap(),
+            "Тошкентда ўн икки та мактаб, 998336523409"
+        );
+        assert_eq!(
+            numbers_to_word("12 ta", Some(Alphabet::Cyrillic)).unwrap(),
+            "ўн икки ta"
+        );
+
+        // words are read back
+        for number in ["0", "32", "1024", "3456"] {
+            let words = integer_to_word(number, Alphabet::Cyrillic).unwrap();
+            assert_eq!(word_to_integer(&words).unwrap().to_string(), number);
+        }
+    }
+
+    #[test]
+    fn ordinal_test() {
+        let cases = [
+            ("0", "nolinchi"),
+            ("1", "birinchi"),
+            ("2", "ikkinchi"),
+            ("20", "yigirmanchi"),
+            ("40", "qirqinchi"),
+            ("50", "elliginchi"),
+            ("104", "bir yuz to‘rtinchi"),
+            ("1000", "bir minginchi"),
+        ];
+
+        for (number, expected) in cases {
+            assert_eq!(to_ordinal(number, Alphabet::Latin).unwrap(), expected);
+        }
+        assert_eq!(to_ordinal("3", Alphabet::Cyrillic).unwrap(), "учинчи");
+        assert!(to_ordinal("3-", Alphabet::Latin).is_err());
+        assert!(to_ordinal("-3", Alphabet::Latin).is_err());
+        assert!(to_ordinal("+3", Alphabet::Latin).is_err());
+    }
+
+    #[test]
+    fn collective_test() {
+        let cases = [
+            ("2", CollectiveForm::Ov, "ikkov"),
+            ("4", CollectiveForm::Ov, "to‘rtov"),
+            ("6", CollectiveForm::Ov, "oltov"),
+            ("3", CollectiveForm::Ala, "uchala"),
+            ("7", CollectiveForm::Ala, "yettala"),
+        ];
+
+        for (number, form, expected) in cases {
+            assert_eq!(
+                to_collective(number, form, Alphabet::Latin).unwrap(),
+                expected
+            );
+        }
+        assert!(to_collective("1", CollectiveForm::Ov, Alphabet::Latin).is_err());
+        assert!(to_collective("11", CollectiveForm::Ala, Alphabet::Latin).is_err());
+        assert!(to_collective("-2", CollectiveForm::Ov, Alphabet::Latin).is_err());
+        assert!(to_collective("+2", CollectiveForm::Ala, Alphabet::Latin).is_err());
+    }
+
+    #[test]
+    fn distributive_test() {
+        assert_eq!(to_distributive("1", Alphabet::Latin).unwrap(), "birtadan");
+        assert_eq!(
+            to_distributive("100", Alphabet::Latin).unwrap(),
+            "bir yuztadan"
+        );
+        assert_eq!(
+            to_distributive("2", Alphabet::Cyrillic).unwrap(),
+            "иккитадан"
+        );
+        assert!(to_distributive("-2", Alphabet::Latin).is_err());
+        assert!(to_distributive("−2", Alphabet::Latin).is_err());
+    }
+
+    #[test]
+    fn ordinals_in_text_test() {
+        let input = "5-sinf, 3-chi qator, 2-inchi 12-yanvar 2023-2024-yillar 1.5-2 10-";
+        let expected = "beshinchi sinf, uchinchi qator, ikkinchi o‘n ikkinchi yanvar ikki ming yigirma uch-ikki ming yigirma to‘rtinchi yillar bir butun o‘ndan besh-ikki o‘n-";
+        assert_eq!(numbers_to_word(input, None).unwrap(), expected);
+    }
+
+    #[test]
+    fn entities_test() {
+        let input = "2022-yil 13-yanvar soat 12:30 da narx 15% ga oshdi: $12.50 yoki 5 000 so‘m.";
+        let expected = "ikki ming yigirma ikkinchi yil o‘n uchinchi yanvar soat o‘n ikki yu o‘ttiz daqiqa da narx o‘n besh foiz ga oshdi: o‘n ikki dollar ellik sent yoki besh ming so‘m.";
+        assert_eq!(numbers_to_word(input, None).unwrap(), expected);
+
+        let input = "Ҳужжат 13.01.2022 да 10% га";
+        let expected = "Ҳужжат икки минг йигирма иккинчи йил ўн учинчи январ да ўн фоиз га";
+        assert_eq!(numbers_to_word(input, None).unwrap(), expected);
+    }
+
+    #[test]
+    fn roman_numerals_test() {
+        let input = "XXI asr 2-yarmi, III bob";
+        let options = NumberOptions {
+            roman_numerals: true,
+            ..NumberOptions::default()
+        };
+
+        assert_eq!(
+            numbers_to_word_with(input, &options).unwrap(),
+            "yigirma birinchi asr ikkinchi yarmi, uchinchi bob"
+        );
+        assert_eq!(
+            numbers_to_word(input, None).unwrap(),
+            "XXI asr ikkinchi yarmi, III bob"
+        );
+    }
+
+    #[test]
+    fn protections_test() {
+        let input =
+            "Tel: (90) 123-45-67, pasport AA1234567, karta 8600 1234 5678 9012, v1.2.3, 5 kitob";
+
+        assert_eq!(
+            numbers_to_word(input, None).unwrap(),
+            "Tel: (90) 123-45-67, pasport AA1234567, karta 8600 1234 5678 9012, v1.2.3, besh kitob"
+        );
+
+        let options = NumberOptions {
+            protect_postal_codes: true,
+            protect_dates: true,
+            ..NumberOptions::default()
+        };
+        assert_eq!(
+            numbers_to_word_with("100011 Toshkent, 13.01.2022 da 3 kun", &options).unwrap(),
+            "100011 Toshkent, 13.01.2022 da uch kun"
+        );
+
+        let options = NumberOptions {
+            protect_phones: false,
+            protect_versions: false,
+            ..NumberOptions::default()
+        };
+        assert_eq!(
+            numbers_to_word_with("90 123 45 67", &options).unwrap(),
+            "to‘qson bir yuz yigirma uch qirq besh oltmish yetti"
+        );
+        // long numbers with no context are numbers
+        for number in ["31234567890123", "1000000000000000"] {
+            assert_eq!(
+                numbers_to_word(number, None).unwrap(),
+                integer_to_word(number, Alphabet::Latin).unwrap()
+            );
+        }
+        assert_eq!(
+            numbers_to_word("PINFL 31234567890123", None).unwrap(),
+            "PINFL 31234567890123"
+        );
+
+        assert_eq!(
+            numbers_to_word("100011 va 13.01.2022", None).unwrap(),
+            "bir yuz ming o‘n bir va ikki ming yigirma ikkinchi yil o‘n uchinchi yanvar"
+        );
+    }
+
+    #[test]
+    fn word_to_integer_test() {
+        for number in [
+            "0",
+            "9",
+            "32",
+            "104",
+            "1024",
+            "3456",
+            "1234567",
+            "999999999999999999",
+        ] {
+            let words = integer_to_word(number, Alphabet::Latin).unwrap();
+            assert_eq!(
+                word_to_integer(&words).unwrap().to_string(),
+                number,
+                "{words}"
+            );
+        }
+
+        assert_eq!(word_to_integer("O‘N IKKI").unwrap(), 12);
+        assert_eq!(word_to_integer("yuz ming").unwrap(), 100_000);
+        assert!(word_to_integer("").is_err());
+        assert!(word_to_integer("salom").is_err());
+        assert!(word_to_integer("ming kvintillion").is_err());
+        assert_eq!(word_to_integer("minus yuz").unwrap(), -100);
+    }
+
+    #[test]
+    fn word_to_float_test() {
+        for number in ["3.0", "3.75", "3.754", "3.7548", "574.789", "0.05"] {
+            let words = float_to_word(number, Alphabet::Latin).unwrap();
+            assert_eq!(
+                word_to_float(&words).unwrap(),
+                number.parse::<f64>().unwrap(),
+                "{words}"
+            );
+        }
+
+        assert!(word_to_float("bir butun o‘ndan o‘n").is_err());
+        assert!(word_to_float("bir butun").is_err());
+        assert!(word_to_float("o‘n ikki").is_err());
+    }
+
+    #[test]
+    fn words_to_numbers_test() {
+        let input =
+            "Hisobga yuz ming so‘m, keyin ikki yuz ellik besh butun yuzdan o‘n, bir kuni yuz";
+        let expected = "Hisobga 100000 so‘m, keyin 255.10, bir kuni yuz";
+        assert_eq!(words_to_numbers(input), expected);
+
+        // bir is an article before kuni, oz and the like, but not in a longer number
+        assert_eq!(
+            words_to_numbers("Бир оз кутдик, bir marta, bir kitob, o‘n bir kuni"),
+            "Бир оз кутдик, bir marta, 1 kitob, 11 kuni"
+        );
+
+        assert_eq!(words_to_numbers("Ўн икки ва\nуч"), "12 ва\n3");
+
+        // digits come back after converting them into words
+        let input = "12, salom -12.5 daraxt 1024 va -3";
+        assert_eq!(
+            words_to_numbers(&numbers_to_word(input, None).unwrap()),
+            input
+        );
     }
 }
@@ -1,5 +1,3 @@
-#![allow(unused)]
-
 pub(super) const NUM_1_TO_19: &[(i32, &str)] = &[
     (1, "bir"),
     (2, "ikki"),
@@ -33,35 +31,63 @@ pub(super) const TEEN: &[(i32, &str)] = &[
     (90, "to‘qson")
 ];
 
-pub(super) const MULT: &[(i32, &str)] = &[
-    (2, "yuz"),
-    (3, "ming"),
-    (6, "million"),
-    (9, "milliard"),
-    (12, "trillion"),
-    (15, "kvadrillion"),
-    (18, "kvintilion"),
+// names of 10^3, 10^6, 10^9 and so on
+pub(super) const SCALES: &[&str] = &[
+    "ming",
+    "million",
+    "milliard",
+    "trillion",
+    "kvadrillion",
+    "kvintillion",
+    "sekstillion",
+    "septillion",
+    "oktillion",
+    "nonillion",
+    "detsillion",
+];
+
+// words after a Roman numeral that make it an ordinal (XXI asr, III bob), regular expressions
+pub(super) const ROMAN_CONTEXT: &[&str] = &[
+    "asr",
+    "bob",
+    "qism",
+    "jild",
+    "tom",
+    "bo[ʻʼ'‘’‛′ʽ`]lim",
+    "mingyillik",
+    "аср",
+    "боб",
+    "қисм",
+    "жилд",
+    "том",
+    "бўлим",
+    "мингйиллик",
 ];
 
-pub(super) const FLOAT_PREFIX: &[&str] = &[
-    ("o‘ndan"),
-    ("yuzdan"),
-    ("mingdan"),
-    ("o‘n mingdan"),
-    ("yuz mingdan"),
-    ("milliondan"),
-    ("o‘n milliondan"),
-    ("yuz milliondan"),
-    ("milliarddan"),
-    ("o‘n milliarddan"),
-    ("yuz milliarddan"),
-    ("trilliondan"),
-    ("o‘n trilliondan"),
-    ("yuz trilliondan"),
-    ("kvadrilliondan"),
-    ("o‘n kvadrilliondan"),
-    ("yuz kvadrilliondan"),
-    ("kvintiliondan")
+// endings of the words above: plural, possessive and case suffixes (asrda, bobining, бўлими),
+// a regular expression; any other letters make another word (tomon, bobo)
+pub(super) const ROMAN_CONTEXT_ENDINGS: &str = concat!(
+    "(?:lar|лар)?",
+    "(?:imiz|ingiz|im|ing|i|имиз|ингиз|им|инг|и)?",
+    "(?:ning|ni|gacha|ga|ka|dagi|dan|da|нинг|ни|гача|га|ка|даги|дан|да)?",
+);
+
+// words after a lone "bir" that make it an article rather than a number (bir kuni, bir oz),
+// written the way normalize_word returns them
+pub(super) const BIR_ARTICLE: &[&str] = &[
+    "kuni",
+    "marta",
+    "oz",
+    "ozgina",
+    "necha",
+    "xil",
+    "zum",
+    "lahza",
+    "safar",
+    "yo'la",
+    "qancha",
+    "paytlar",
+    "vaqtlar",
 ];
 
 pub(super) const WORD_0_TO_90: &[(&str, i32)] = &[
@@ -0,0 +1,206 @@
+//! Roman numerals, used for centuries and chapters (XXI asr, III bob).
+use super::helper::{self, Conversion};
+use super::{prelude, to_ordinal};
+use crate::error::KorrektorError;
+use crate::uzbek::Alphabet;
+use fancy_regex::Regex;
+use once_cell::sync::Lazy;
+
+const NUMERALS: &[(u32, &str)] = &[
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// Returns a Roman numeral of a given integer number.
+///
+/// Given an integer string slice from 1 to 3999 returns a String with
+/// corresponding Roman numeral.
+///
+/// # Errors
+/// - [`KorrektorError::InvalidNumber`] if a string is not a valid positive integer
+/// - [`KorrektorError::NumberOverflow`] if it is greater than 3999
+///
+/// # Example
+/// ```rust
+///use korrektor::uzbek::number;
+///
+/// assert_eq!(number::integer_to_roman("21").unwrap(), "XXI".to_string());
+/// assert_eq!(number::integer_to_roman("1994").unwrap(), "MCMXCIV".to_string());
+/// ```
+pub fn integer_to_roman(number: &str) -> Result<String, KorrektorError> {
+    let digits = match helper::split_integer(number) {
+        Some((None, digits)) if digits != "0" => digits,
+        _ => {
+            return Err(KorrektorError::InvalidNumber(
+                number.to_string(),
+                "Not a valid positive integer".to_string(),
+            ))
+        }
+    };
+
+    match digits.parse::<u32>() {
+        Ok(value @ 1..=3999) => Ok(to_roman(value)),
+        _ => Err(KorrektorError::NumberOverflow(
+            number.to_string(),
+            "Roman numerals are written for numbers up to 3999".to_string(),
+        )),
+    }
+}
+
+/// Returns the value of a Roman numeral.
+///
+/// Accepts numerals in the standard subtractive form in any case.
+///
+/// # Errors
+/// Returns [`KorrektorError::InvalidNumber`] if a string is not a valid
+/// Roman numeral (`IIII` and `IC` are not).
+///
+/// # Example
+/// ```rust
+///use korrektor::uzbek::number;
+///
+/// assert_eq!(number::roman_to_integer("XXI").unwrap(), 21);
+/// assert_eq!(number::roman_to_integer("mcmxciv").unwrap(), 1994);
+/// assert!(number::roman_to_integer("IIII").is_err());
+/// ```
+pub fn roman_to_integer(roman: &str) -> Result<i64, KorrektorError> {
+    let invalid = || {
+        KorrektorError::InvalidNumber(roman.to_string(), "Not a valid Roman numeral".to_string())
+    };
+
+    let upper = roman.to_uppercase();
+    let mut rest = upper.as_str();
+    let mut value = 0;
+
+    // the largest numerals first, the result is checked to be the standard form below
+    for (number, numeral) in NUMERALS {
+        while let Some(stripped) = rest.strip_prefix(numeral) {
+            value += number;
+            rest = stripped;
+        }
+    }
+
+    if !rest.is_empty() || !(1..=3999).contains(&value) || to_roman(value) != upper {
+        return Err(invalid());
+    }
+
+    Ok(value as i64)
+}
+
+// a Roman numeral followed by a context word
+static ROMAN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r"(?<![\p{{L}}0-9])([IVXLCDM]+)(?:\s+|-)((?i:(?:{}){})(?!\p{{L}}))",
+        prelude::ROMAN_CONTEXT.join("|"),
+        prelude::ROMAN_CONTEXT_ENDINGS
+    ))
+    .unwrap()
+});
+
+// XXI asr, III-bob into ordinals, numerals without a context word are left as they are
+pub(super) fn romans(alphabet: Alphabet) -> Conversion {
+    (
+        &ROMAN,
+        Box::new(move |captures| match roman_to_integer(&captures[1]) {
+            Ok(value) => Ok(format!(
+                "{} {}",
+                to_ordinal(&value.to_string(), alphabet)?,
+                &captures[2]
+            )),
+            Err(_) => Ok(captures[0].to_string()),
+        }),
+    )
+}
+
+fn to_roman(mut value: u32) -> String {
+    let mut roman = String::new();
+
+    for (number, numeral) in NUMERALS {
+        while value >= *number {
+            roman.push_str(numeral);
+            value -= number;
+        }
+    }
+
+    roman
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    fn convert_romans(text: &str, alphabet: Alphabet) -> Result<String, KorrektorError> {
+        helper::convert(text, &[romans(alphabet)])
+    }
+
+    #[test]
+    fn to_roman_test() {
+        let cases = [
+            (1, "I"),
+            (4, "IV"),
+            (9, "IX"),
+            (14, "XIV"),
+            (40, "XL"),
+            (90, "XC"),
+            (400, "CD"),
+            (2024, "MMXXIV"),
+            (3999, "MMMCMXCIX"),
+        ];
+
+        for (value, roman) in cases {
+            assert_eq!(to_roman(value), roman);
+            assert_eq!(roman_to_integer(roman).unwrap(), value as i64);
+        }
+    }
+
+    #[test]
+    fn errors_test() {
+        for number in ["0", "-5", "V", ""] {
+            match integer_to_roman(number) {
+                Err(KorrektorError::InvalidNumber(..)) => {}
+                other => panic!("expected an error for {number:?}, got {other:?}"),
+            }
+        }
+        assert!(matches!(
+            integer_to_roman("4000"),
+            Err(KorrektorError::NumberOverflow(..))
+        ));
+
+        for roman in ["", "IIII", "VV", "IC", "XM", "MMMM", "ABC", "X I"] {
+            assert!(roman_to_integer(roman).is_err(), "{roman}");
+        }
+    }
+
+    #[test]
+    fn convert_romans_test() {
+        assert_eq!(
+            convert_romans("XXI asrda, III-bob va IV Qism", Alphabet::Latin).unwrap(),
+            "yigirma birinchi asrda, uchinchi bob va to‘rtinchi Qism"
+        );
+        assert_eq!(
+            convert_romans("XX асрнинг II бўлими", Alphabet::Cyrillic).unwrap(),
+            "йигирманчи асрнинг иккинчи бўлими"
+        );
+        assert_eq!(
+            convert_romans("V tomining XII boblari", Alphabet::Latin).unwrap(),
+            "beshinchi tomining o‘n ikkinchi boblari"
+        );
+        // ordinary capital letters and invalid numerals
+        let text = "I va men, CD disk, IIII asr, MIX sharbat";
+        assert_eq!(convert_romans(text, Alphabet::Latin).unwrap(), text);
+        // words that only start like a context word
+        let text = "C tomonidan, D tomonga, V bobosi, II asrorlar, X томонидан";
+        assert_eq!(convert_romans(text, Alphabet::Latin).unwrap(), text);
+    }
+}
@@ -0,0 +1,275 @@
+//! Break points of words by syllables.
+use super::words::{words, TokenKind};
+use super::{a_correct, create_map, prelude};
+use crate::utils::pcre as ipcre;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+// A_CORRECT anchored at the start, a letter of the syllable rules for every match
+static LETTERS: Lazy<Vec<(Regex, char)>> = Lazy::new(|| {
+    prelude::A_CORRECT
+        .iter()
+        .map(|(pattern, letter)| {
+            let regex = Regex::new(&format!("^(?:{pattern})")).unwrap();
+            (regex, letter.chars().next().unwrap())
+        })
+        .collect()
+});
+
+static LATIN: Lazy<Regex> = Lazy::new(|| Regex::new(r"\p{Latin}").unwrap());
+static CYRILLIC: Lazy<Regex> = Lazy::new(|| Regex::new(r"\p{Cyrillic}").unwrap());
+
+/// Settings of [`hyphenate`].
+#[derive(Debug, Clone)]
+pub struct HyphenOptions {
+    /// Fewest letters of a word before the first hyphen, 2 by default.
+    pub min_prefix: usize,
+    /// Fewest letters of a word after the last hyphen, 2 by default.
+    pub min_suffix: usize,
+    /// Character inserted at every break, a soft hyphen (U+00AD) by default.
+    pub hyphen: char,
+    /// Allow a single letter before the first or after the last hyphen even if
+    /// `min_prefix` or `min_suffix` is 1, `false` by default.
+    pub single_letters: bool,
+}
+
+impl Default for HyphenOptions {
+    fn default() -> Self {
+        HyphenOptions {
+            min_prefix: 2,
+            min_suffix: 2,
+            hyphen: '\u{00AD}',
+            single_letters: false,
+        }
+    }
+}
+
+/// Returns the break points of a word by syllables.
+///
+/// Break points are byte offsets into the word where a syllable starts,
+/// except the first one. Digraphs (`sh`, `ch`) and letters with an apostrophe
+/// (`o‘`, `g‘`) are never broken, parts of a compound word (`ota-ona`)
+/// are split separately.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::tokenize;
+///
+/// assert_eq!(tokenize::syllable_boundaries("chiroyli"), vec![3, 6]);
+/// assert_eq!(tokenize::syllable_boundaries("O‘zbek"), vec![5]);
+/// assert_eq!(tokenize::syllable_boundaries("чиройли"), vec![4, 10]);
+/// ```
+pub fn syllable_boundaries(word: &str) -> Vec<usize> {
+    parts(word)
+        .into_iter()
+        .flat_map(|(offsets, breaks)| breaks.into_iter().map(move |index| offsets[index]))
+        .collect()
+}
+
+/// Inserts a hyphen at the break points of the words in text.
+///
+/// Only the breaks that leave enough letters of a word on both lines are used,
+/// see [`HyphenOptions`]. Punctuation, numbers, URLs and other non-words
+/// are kept as they are.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::tokenize::{self, HyphenOptions};
+///
+/// let options = HyphenOptions {
+///     hyphen: '-',
+///     ..HyphenOptions::default()
+/// };
+///
+/// let output = tokenize::hyphenate("Chiroyli o‘rik, ota-ona!", &options);
+/// let expected = "Chi-roy-li o‘rik, ota-ona!".to_string();
+/// assert_eq!(output, expected);
+///
+/// let output = tokenize::hyphenate("chiroyli", &HyphenOptions::default());
+/// assert_eq!(output, "chi\u{00AD}roy\u{00AD}li");
+/// ```
+pub fn hyphenate(text: &str, options: &HyphenOptions) -> String {
+    let shortest = if options.single_letters { 1 } else { 2 };
+    let min_prefix = options.min_prefix.max(shortest);
+    let min_suffix = options.min_suffix.max(shortest);
+
+    let mut result = String::new();
+
+    for token in words(text) {
+        if token.kind != TokenKind::Word {
+            result.push_str(&token.text);
+            continue;
+        }
+
+        let mut last = 0;
+        for (offsets, breaks) in parts(&token.text) {
+            for index in breaks {
+                if index >= min_prefix && offsets.len() - index >= min_suffix {
+                    result.push_str(&token.text[last..offsets[index]]);
+                    result.push(options.hyphen);
+                    last = offsets[index];
+                }
+            }
+        }
+        result.push_str(&token.text[last..]);
+    }
+
+    result
+}
+
+// letters of every part of a compound word: byte offsets of the letters in the word
+// and indexes of the letters that start a syllable, except the first one
+fn parts(word: &str) -> Vec<(Vec<usize>, Vec<usize>)> {
+    let mut parts = vec![];
+    let mut start = 0;
+
+    for part in word.split('-') {
+        let (offsets, letters) = letters(part);
+        let offsets: Vec<usize> = offsets.iter().map(|offset| start + offset).collect();
+        let breaks = breaks(&letters);
+
+        start += part.len() + 1;
+        parts.push((offsets, breaks));
+    }
+
+    parts
+}
+
+// letters of a word the way the syllable rules see them: lowercase, digraphs and
+// letters with an apostrophe as a single char, along with their byte offsets;
+// a char that lowercases into several chars (`İ`) keeps only the first of them
+fn letters(word: &str) -> (Vec<usize>, String) {
+    let mut lowercase = String::new();
+    let mut origins = vec![];
+    for (offset, char) in word.char_indices() {
+        if let Some(lower) = char.to_lowercase().next() {
+            origins.push((lowercase.len(), offset));
+            lowercase.push(lower);
+        }
+    }
+
+    let mut offsets = vec![];
+    let mut letters = String::new();
+
+    let mut index = 0;
+    while index < origins.len() {
+        let (position, offset) = origins[index];
+        let rest = &lowercase[position..];
+
+        let (letter, length) = LETTERS
+            .iter()
+            .find_map(|(regex, letter)| {
+                regex
+                    .find(rest)
+                    .map(|found| (*letter, found.as_str().chars().count()))
+            })
+            .unwrap_or_else(|| (rest.chars().next().unwrap(), 1));
+
+        offsets.push(offset);
+        letters.push(letter);
+        index += length;
+    }
+
+    (offsets, letters)
+}
+
+// indexes of the letters that start a syllable, except the first one
+fn breaks(letters: &str) -> Vec<usize> {
+    let exception = prelude::LATIN_EXP
+        .iter()
+        .chain(prelude::CYRILLIC_EXP)
+        .find(|(word, _)| *word == letters);
+
+    let lengths: Vec<usize> = match exception {
+        Some((_, syllables)) => syllables
+            .split('-')
+            .map(|syllable| a_correct(syllable).chars().count())
+            .collect(),
+        None => {
+            let mut pattern = letters.to_string();
+            if LATIN.is_match(letters) {
+                pattern = ipcre::replace_pairs(&pattern, prelude::REPLACE_LAT);
+            }
+            if CYRILLIC.is_match(letters) {
+                pattern = ipcre::replace_pairs(&pattern, prelude::REPLACE_CYR);
+            }
+
+            create_map(&pattern)
+                .into_iter()
+                .map(|length| length as usize)
+                .collect()
+        }
+    };
+
+    let count = letters.chars().count();
+    lengths
+        .iter()
+        .scan(0, |start, length| {
+            *start += length;
+            Some(*start)
+        })
+        .filter(|index| *index < count)
+        .collect()
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    #[test]
+    fn letters_test() {
+        assert_eq!(letters("O‘g‘il"), (vec![0, 4, 8, 9], String::from("ŏğil")));
+        assert_eq!(
+            letters("SHahar"),
+            (vec![0, 2, 3, 4, 5], String::from("šahar"))
+        );
+        assert_eq!(
+            letters("ma'no"),
+            (vec![0, 1, 2, 3, 4], String::from("maʼno"))
+        );
+    }
+
+    #[test]
+    fn syllable_boundaries_test() {
+        assert_eq!(syllable_boundaries("singil"), vec![2]);
+        assert_eq!(syllable_boundaries("Peshayvon"), vec![2]);
+        assert_eq!(syllable_boundaries("CHIROYLI"), vec![3, 6]);
+        assert_eq!(syllable_boundaries("maktab"), vec![3]);
+        assert_eq!(syllable_boundaries("ota-ona"), vec![1, 5]);
+        assert_eq!(syllable_boundaries("İstanbul"), vec![3, 6]);
+        assert_eq!(syllable_boundaries("a"), Vec::<usize>::new());
+        assert_eq!(syllable_boundaries(""), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn hyphenate_test() {
+        let dash = HyphenOptions {
+            hyphen: '-',
+            ..HyphenOptions::default()
+        };
+        assert_eq!(hyphenate("ota-ona, aka", &dash), "ota-ona, aka");
+        assert_eq!(hyphenate("O‘ZBEKISTON 2024", &dash), "O‘Z-BE-KIS-TON 2024");
+
+        let long = HyphenOptions {
+            min_prefix: 4,
+            min_suffix: 3,
+            ..dash.clone()
+        };
+        assert_eq!(
+            hyphenate("maktabimiz chiroyli", &long),
+            "makta-bi-miz chiroyli"
+        );
+
+        let short = HyphenOptions {
+            min_prefix: 1,
+            min_suffix: 1,
+            ..dash.clone()
+        };
+        assert_eq!(hyphenate("ota-ona", &short), "ota-ona");
+        let single = HyphenOptions {
+            single_letters: true,
+            ..short
+        };
+        assert_eq!(hyphenate("ota-ona", &single), "o-ta-o-na");
+    }
+}
@@ -1,109 +1,65 @@
-//! Functions to split Uzbek words by syllables.
+//! Functions to split Uzbek text into sentences, words and words by syllables.
 //!
 //! Implemented according to grammar rules
 //! for both latin and cyrillic modes.
+mod hyphenation;
 mod prelude;
+mod sentences;
+mod words;
+
+pub use hyphenation::{hyphenate, syllable_boundaries, HyphenOptions};
+pub use sentences::{sentences, Sentence};
+pub use words::{words, Token, TokenKind};
 
 use crate::utils::pcre as ipcre;
-use pcre::Pcre;
-use regex::Regex;
 
 /// Splits all the words in text by syllables.
 ///
 /// Given a String reference returns a new String
 /// containing the words separated by syllables with a delimiter.
-/// Words in the input should be separated with a whitespace.
-/// Single word may be not padded with a whitespace.
+/// Only the delimiters are inserted: letters keep their case and apostrophes,
+/// punctuation, numbers, URLs and other non-words are kept as they are.
 ///
 /// # Example
 /// ```rust
 /// use korrektor::uzbek::tokenize;
 ///
-/// let output = tokenize::split_text("singil chiroyli чиройли");
-/// let expected = "si-ngil chi-roy-li чи-рой-ли".to_string();
+/// let output = tokenize::split_text("Singil, CHIROYLI чиройли o'zbek");
+/// let expected = "Si-ngil, CHI-ROY-LI чи-рой-ли o'z-bek".to_string();
 /// assert_eq!(output, expected);
 /// ```
 pub fn split_text(text: &str) -> String {
     let mut result = String::new();
 
-    let words = text.split_whitespace();
-    for word in words {
-        result = result + &split_word(word) + " ";
+    for token in words(text.trim()) {
+        match token.kind {
+            TokenKind::Word => result.push_str(&split_word(&token.text)),
+            _ => result.push_str(&token.text),
+        }
     }
 
-    result.trim().to_string()
+    result
 }
 
+// letters of the word are kept as they are, only the separators are inserted
 fn split_word(word: &str) -> String {
-    let mut result = a_correct(word);
-    result = result.trim().to_string();
-    let mut last = result.clone();
-
-    let copy = result.clone();
-
-    // latin implementation
-    let mut re = Pcre::compile(r"(?:\p{Latin}+)").unwrap();
-    let matches = re.matches(&copy);
-
-    // if found latin word
-    for _ in matches {
-        let key = prelude::LATIN_EXP.iter().find(|k| k.0 == copy);
-        if let Some(pair) = key {
-            return pair.1.to_string();
-        }
-
-        for pair in prelude::REPLACE_LAT {
-            let re = Regex::new(pair.0).unwrap();
-            result = re.replace_all(&result, pair.1).as_ref().to_string();
-        }
-    }
-
-    // cyrillic implementation
-    let mut re = Pcre::compile(r"(?:\p{Cyrillic}+)").unwrap();
-    let matches = re.matches(&copy);
-
-    // if found cyrillic word
-    for _ in matches {
-        let key = prelude::CYRILLIC_EXP.iter().find(|k| k.0 == copy);
-        if let Some(pair) = key {
-            return pair.1.to_string();
-        }
-
-        for pair in prelude::REPLACE_CYR {
-            let re = Regex::new(pair.0).unwrap();
-            result = re.replace_all(&result, pair.1).as_ref().to_string();
-        }
-    }
-
-    let textmap = create_map(&result);
-
     let mut result = String::new();
+    let mut last = 0;
 
-    for i in 0..textmap.len() {
-        let first: Vec<char> = last.chars().collect();
-        let first: String = first[0..textmap[i] as usize].iter().collect();
-        let second: Vec<char> = last.chars().collect();
-        let second: String = second[textmap[i] as usize..].iter().collect();
-        last = second;
-
-        if i == 0 {
-            result = result + &first;
-        } else {
-            result = result + "-" + &first;
-        }
+    for boundary in syllable_boundaries(word) {
+        result.push_str(&word[last..boundary]);
+        result.push('-');
+        last = boundary;
     }
+    result.push_str(&word[last..]);
 
-    i_correct(&result)
+    result
 }
 
 fn a_correct(text: &str) -> String {
     ipcre::replace_pairs(&text.to_lowercase(), prelude::A_CORRECT)
 }
 
-fn i_correct(text: &str) -> String {
-    ipcre::replace_pairs(text, prelude::I_CORRECT)
-}
-
 enum Split {
     One,
     Two,
@@ -255,14 +211,6 @@ mod as_tests {
         );
     }
 
-    #[test]
-    fn i_correct_test() {
-        assert_eq!(
-            i_correct(&String::from("ğ ŏ š č")),
-            String::from("gʻ o‘ sh ch")
-        );
-    }
-
     #[test]
     fn create_map_test() {
         assert_eq!(create_map("CVCCVCCVCVC"), vec![3, 3, 2, 3]);
@@ -273,6 +221,14 @@ mod as_tests {
         assert_eq!(split_word("singil"), "si-ngil");
         assert_eq!(split_word("chiroyli"), "chi-roy-li");
         assert_eq!(split_word("чиройли"), "чи-рой-ли");
+        // case and apostrophes as they are
+        assert_eq!(split_word("Chiroyli"), "Chi-roy-li");
+        assert_eq!(split_word("CHIROYLI"), "CHI-ROY-LI");
+        assert_eq!(split_word("SHAHAR"), "SHA-HAR");
+        assert_eq!(split_word("O'zbekiston"), "O'z-be-kis-ton");
+        assert_eq!(split_word("G`ulshan"), "G`ul-shan");
+        assert_eq!(split_word("Чиройли"), "Чи-рой-ли");
+        assert_eq!(split_word("İstanbul"), "İs-tan-bul");
     }
 
     #[test]
@@ -284,5 +240,9 @@ mod as_tests {
         assert_eq!(split_text("singil"), "si-ngil");
         assert_eq!(split_text("chiroyli"), "chi-roy-li");
         assert_eq!(split_text("чиройли"), "чи-рой-ли");
+        assert_eq!(
+            split_text("singil, chiroyli! 12 ta"),
+            "si-ngil, chi-roy-li! 12 ta"
+        );
     }
 }
@@ -18,13 +18,6 @@ pub(super) const A_CORRECT: &[(&str, &str)] = &[
     ("ch", "č")
 ];
 
-pub(super) const I_CORRECT: &[(&str, &str)] = &[
-    ("ğ", "gʻ"),
-    ("ŏ", "o‘"),
-    ("š", "sh"),
-    ("č", "ch")
-];
-
 pub(super) const REPLACE_CYR: &[(&str, &str)] = &[
     ("[аоуэияёюеў]", "V"),
     ("[бвгджзйклмнпрстфхцчшқғҳ]", "C")
@@ -33,4 +26,4 @@ pub(super) const REPLACE_CYR: &[(&str, &str)] = &[
 pub(super) const REPLACE_LAT: &[(&str, &str)] = &[
     ("[aoueiŏ]", "V"),
     ("[bdfghjklmnpqrstvxyzğšč]", "C")
-];
\ No newline at end of file
+];
@@ -0,0 +1,261 @@
+//! Splitting text into sentences.
+use super::words::{words, Token, TokenKind};
+use crate::uzbek::alphabet::APOSTROPHES;
+use crate::uzbek::corrector::prelude::ABBREVIATIONS;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+const TERMINATORS: &[&str] = &[".", "!", "?", "…"];
+const CLOSING: &[&str] = &["»", "”", "\"", ")", "’"];
+const OPENING: &[&str] = &["«", "“", "\"", "("];
+const DASHES: &[&str] = &["—", "–", "-"];
+
+/// Sentence returned by [`sentences`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sentence {
+    pub text: String,
+    /// Byte range of the sentence in the original text,
+    /// whitespace around the sentence is not included.
+    pub span: Range<usize>,
+}
+
+/// Splits text into sentences.
+///
+/// A sentence ends with `.`, `!`, `?` or `…` (and the closing quotes
+/// right after them) followed by a word starting with a capital letter,
+/// a number, an opening quote or a dash before a capitalized word
+/// (a dash before a lowercase word is a remark of the speaker, like
+/// `«Salom!» — dedi u.`). Empty lines always end a sentence.
+///
+/// A period after an initial (`A. Navoiy`) or an abbreviation like `y.`
+/// or `t.y.` does not end a sentence, neither do periods of decimal
+/// numbers (`3.5`). Other abbreviations (`va h.k.`) end a sentence
+/// only if a capitalized word follows.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::tokenize;
+///
+/// let text = "A. Navoiy 1441 y. tug‘ilgan. U «Xamsa»ni yozgan!  Qalam, daftar va h.k. bor.";
+/// let sentences: Vec<String> = tokenize::sentences(text)
+///     .into_iter()
+///     .map(|sentence| sentence.text)
+///     .collect();
+///
+/// assert_eq!(
+///     sentences,
+///     vec![
+///         "A. Navoiy 1441 y. tug‘ilgan.",
+///         "U «Xamsa»ni yozgan!",
+///         "Qalam, daftar va h.k. bor.",
+///     ]
+/// );
+/// ```
+pub fn sentences(text: &str) -> Vec<Sentence> {
+    let tokens = words(text);
+    let mut sentences: Vec<Sentence> = vec![];
+
+    // (start of the sentence, end of its last token)
+    let mut current: Option<(usize, usize)> = None;
+    let mut close = |current: &mut Option<(usize, usize)>| {
+        if let Some((start, end)) = current.take() {
+            sentences.push(Sentence {
+                text: text[start..end].to_string(),
+                span: start..end,
+            });
+        }
+    };
+
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = &tokens[index];
+
+        if token.kind == TokenKind::Whitespace {
+            if token.text.matches('\n').count() > 1 {
+                close(&mut current);
+            }
+            index += 1;
+            continue;
+        }
+
+        let start = current.map_or(token.span.start, |(start, _)| start);
+        current = Some((start, token.span.end));
+
+        if !is_any(token, TERMINATORS) {
+            index += 1;
+            continue;
+        }
+
+        // terminators and closing quotes right after them belong to the sentence
+        let mut end = index + 1;
+        while end < tokens.len()
+            && (is_any(&tokens[end], TERMINATORS) || is_any(&tokens[end], CLOSING))
+        {
+            end += 1;
+        }
+        current = Some((start, tokens[end - 1].span.end));
+
+        if ends_sentence(&tokens, index, end) {
+            close(&mut current);
+        }
+        index = end;
+    }
+    close(&mut current);
+
+    sentences
+}
+
+fn is_any(token: &Token, texts: &[&str]) -> bool {
+    token.kind == TokenKind::Punctuation && texts.contains(&token.text.as_str())
+}
+
+/// Whether terminators in `tokens[first..after]` end the sentence.
+fn ends_sentence(tokens: &[Token], first: usize, after: usize) -> bool {
+    let next = match &tokens[after..] {
+        [] => return true,
+        [space, ..] if space.kind != TokenKind::Whitespace => return false,
+        [_] => return true,
+        [_, next, ..] => next,
+    };
+
+    // a single period may belong to an abbreviation or an initial
+    if after - first == 1 && tokens[first].text == "." && first > 0 {
+        let previous = &tokens[first - 1];
+        let is_initial = previous.kind == TokenKind::Word
+            && previous.text.chars().count() == 1
+            && previous.text.chars().all(char::is_uppercase);
+
+        if is_initial || ABBREVIATIONS.contains(&abbreviation(tokens, first).as_str()) {
+            return false;
+        }
+    }
+
+    if is_any(next, DASHES) {
+        return tokens[after + 2..]
+            .iter()
+            .find(|token| token.kind != TokenKind::Whitespace)
+            .is_some_and(is_capitalized);
+    }
+
+    match next.kind {
+        TokenKind::Number => true,
+        TokenKind::Word => is_capitalized(next),
+        TokenKind::Punctuation => OPENING.contains(&next.text.as_str()) || is_apostrophe(next),
+        _ => false,
+    }
+}
+
+fn is_capitalized(token: &Token) -> bool {
+    token.kind == TokenKind::Word && token.text.chars().next().is_some_and(char::is_uppercase)
+}
+
+fn is_apostrophe(token: &Token) -> bool {
+    token.text.chars().all(|char| APOSTROPHES.contains(&char))
+}
+
+/// Lowercased dotted word right before the period at `tokens[period]`, like `t.y`,
+/// with its apostrophes written as `‘` the way [`ABBREVIATIONS`] has them.
+fn abbreviation(tokens: &[Token], period: usize) -> String {
+    let mut parts: Vec<&str> = vec![];
+    let mut index = period;
+
+    while index > 0 && tokens[index - 1].kind == TokenKind::Word {
+        parts.push(&tokens[index - 1].text);
+
+        if index > 1 && tokens[index - 2].text == "." {
+            index -= 2;
+        } else {
+            break;
+        }
+    }
+
+    parts.reverse();
+    parts
+        .join(".")
+        .to_lowercase()
+        .chars()
+        .map(|char| {
+            if APOSTROPHES.contains(&char) {
+                '‘'
+            } else {
+                char
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    fn texts(text: &str) -> Vec<String> {
+        sentences(text)
+            .into_iter()
+            .map(|sentence| sentence.text)
+            .collect()
+    }
+
+    #[test]
+    fn sentences_test() {
+        assert_eq!(
+            texts("Bugun 12-yanvar. Harorat 3.5 daraja... sovuq, lekin quyoshli! Shundaymi?"),
+            vec![
+                "Bugun 12-yanvar.",
+                "Harorat 3.5 daraja... sovuq, lekin quyoshli!",
+                "Shundaymi?"
+            ]
+        );
+
+        // quotes
+        assert_eq!(
+            texts("U: «Keldim.» dedi. “Yaxshi!” Keyin ketdi"),
+            vec!["U: «Keldim.» dedi.", "“Yaxshi!”", "Keyin ketdi"]
+        );
+
+        // abbreviations and initials
+        assert_eq!(
+            texts("Sh. Rashidov t.y. 1917. Toshkent sh. Chilonzor tum. 5-uy. Olma, nok va h.k. Bular mevalar."),
+            vec![
+                "Sh. Rashidov t.y. 1917.",
+                "Toshkent sh. Chilonzor tum. 5-uy.",
+                "Olma, nok va h.k.",
+                "Bular mevalar."
+            ]
+        );
+        assert_eq!(
+            texts("Navoiy ko'ch. Uyda edi."),
+            vec!["Navoiy ko'ch. Uyda edi."]
+        );
+
+        // dashes
+        assert_eq!(
+            texts("«Salom!» — dedi u. Keyin ketdi."),
+            vec!["«Salom!» — dedi u.", "Keyin ketdi."]
+        );
+        assert_eq!(
+            texts("Keldi. — Qani? — dedi u."),
+            vec!["Keldi.", "— Qani? — dedi u."]
+        );
+
+        // cyrillic and empty lines
+        assert_eq!(
+            texts("Тошкент ш. Юнусобод.\n\nЯнги бўлим\nдавоми"),
+            vec!["Тошкент ш. Юнусобод.", "Янги бўлим\nдавоми"]
+        );
+    }
+
+    #[test]
+    fn spans_test() {
+        let text = "  Salom!  Qalaysan?\n";
+        let sentences = sentences(text);
+
+        assert_eq!(sentences.len(), 2);
+        for sentence in &sentences {
+            assert_eq!(&text[sentence.span.clone()], sentence.text);
+        }
+        assert_eq!(sentences[0].span, 2..8);
+
+        assert!(super::sentences("").is_empty());
+        assert!(super::sentences(" \n ").is_empty());
+    }
+}
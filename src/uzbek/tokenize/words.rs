@@ -0,0 +1,269 @@
+//! Splitting text into typed tokens.
+use crate::utils::wrappers::{EMAIL_PATTERN, URL_PATTERN, USERNAME_PATTERN};
+use crate::uzbek::alphabet::APOSTROPHES;
+use fancy_regex::Regex;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+static SPECIAL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        "(?P<email>{EMAIL_PATTERN})|(?P<url>{URL_PATTERN})|(?P<username>{USERNAME_PATTERN})"
+    ))
+    .unwrap()
+});
+
+/// Kind of a [`Token`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TokenKind {
+    /// Letters, along with apostrophes (`o‘`, `maʼno`) and hyphens
+    /// of compound words and enclitics (`ota-ona`, `bor-ku`).
+    Word,
+    /// Digits, along with decimal and thousand separators (`3,5`, `1.000`).
+    Number,
+    /// Single character that is neither a letter, a digit nor a whitespace.
+    Punctuation,
+    Url,
+    Email,
+    Username,
+    Whitespace,
+}
+
+/// Part of a text returned by [`words`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+    /// Byte range of the token in the original text.
+    pub span: Range<usize>,
+}
+
+/// Splits text into tokens.
+///
+/// Tokens cover the whole text, joining their texts gives back the
+/// original one. URLs, emails and usernames are recognized with the
+/// same patterns that protect them from transliteration.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::tokenize::{self, TokenKind};
+///
+/// let tokens = tokenize::words("G‘ozal, bor-ku! @korrektor");
+/// let tokens: Vec<(TokenKind, &str)> = tokens
+///     .iter()
+///     .map(|token| (token.kind, token.text.as_str()))
+///     .collect();
+///
+/// assert_eq!(
+///     tokens,
+///     vec![
+///         (TokenKind::Word, "G‘ozal"),
+///         (TokenKind::Punctuation, ","),
+///         (TokenKind::Whitespace, " "),
+///         (TokenKind::Word, "bor-ku"),
+///         (TokenKind::Punctuation, "!"),
+///         (TokenKind::Whitespace, " "),
+///         (TokenKind::Username, "@korrektor"),
+///     ]
+/// );
+/// ```
+pub fn words(text: &str) -> Vec<Token> {
+    let mut tokens: Vec<Token> = vec![];
+    let mut last = 0;
+
+    for captures in SPECIAL.captures_iter(text).flatten() {
+        let special = captures.get(0).unwrap();
+
+        let kind = if captures.name("email").is_some() {
+            TokenKind::Email
+        } else if captures.name("url").is_some() {
+            TokenKind::Url
+        } else {
+            TokenKind::Username
+        };
+
+        scan(text, last..special.start(), &mut tokens);
+        tokens.push(Token {
+            kind,
+            text: special.as_str().to_string(),
+            span: special.start()..special.end(),
+        });
+        last = special.end();
+    }
+    scan(text, last..text.len(), &mut tokens);
+
+    tokens
+}
+
+/// Tokenizes a part of the text with no URLs, emails and usernames.
+fn scan(text: &str, range: Range<usize>, tokens: &mut Vec<Token>) {
+    let chars: Vec<(usize, char)> = text[range.clone()]
+        .char_indices()
+        .map(|(index, char)| (range.start + index, char))
+        .collect();
+
+    let mut index = 0;
+    while index < chars.len() {
+        let (start, char) = chars[index];
+
+        let (kind, length) = if char.is_whitespace() {
+            let length = chars[index..]
+                .iter()
+                .take_while(|(_, char)| char.is_whitespace())
+                .count();
+            (TokenKind::Whitespace, length)
+        } else if is_letter(char) {
+            (TokenKind::Word, word_length(&chars[index..]))
+        } else if char.is_numeric() {
+            (TokenKind::Number, number_length(&chars[index..]))
+        } else {
+            (TokenKind::Punctuation, 1)
+        };
+
+        let end = chars.get(index + length).map_or(range.end, |(end, _)| *end);
+        tokens.push(Token {
+            kind,
+            text: text[start..end].to_string(),
+            span: start..end,
+        });
+        index += length;
+    }
+}
+
+// modifier letters ʻ and ʼ are alphabetic, but never start a word
+fn is_letter(char: char) -> bool {
+    char.is_alphabetic() && !APOSTROPHES.contains(&char)
+}
+
+fn word_length(chars: &[(usize, char)]) -> usize {
+    let char_at = |index: usize| chars.get(index).map(|(_, char)| *char);
+    let mut length = 1;
+
+    while let Some(char) = char_at(length) {
+        let previous = chars[length - 1].1;
+        let next = char_at(length + 1);
+
+        let joins = if APOSTROPHES.contains(&char) {
+            // o‘ and g‘ even at the end of a word, otherwise in between letters (maʼno)
+            "oOgG".contains(previous) || next.is_some_and(is_letter)
+        } else if char == '-' {
+            // compound words and enclitics (ota-ona, bor-ku, kelgan-chi)
+            next.is_some_and(is_letter)
+        } else {
+            is_letter(char) || char.is_numeric()
+        };
+
+        if !joins {
+            break;
+        }
+        length += 1;
+    }
+
+    length
+}
+
+fn number_length(chars: &[(usize, char)]) -> usize {
+    let char_at = |index: usize| chars.get(index).map(|(_, char)| *char);
+    let mut length = 1;
+
+    while let Some(char) = char_at(length) {
+        let separator = (char == '.' || char == ',')
+            && char_at(length + 1).is_some_and(|next| next.is_numeric());
+
+        if !(char.is_numeric() || separator) {
+            break;
+        }
+        length += 1;
+    }
+
+    length
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    fn kinds(text: &str) -> Vec<(TokenKind, String)> {
+        words(text)
+            .into_iter()
+            .filter(|token| token.kind != TokenKind::Whitespace)
+            .map(|token| (token.kind, token.text))
+            .collect()
+    }
+
+    #[test]
+    fn words_test() {
+        let text = "O‘zbekiston, maʼno va mo''jiza: ota-ona 3,5 kg; 2023-yil \"salom\"";
+        let expected = [
+            (TokenKind::Word, "O‘zbekiston"),
+            (TokenKind::Punctuation, ","),
+            (TokenKind::Word, "maʼno"),
+            (TokenKind::Word, "va"),
+            (TokenKind::Word, "mo''jiza"),
+            (TokenKind::Punctuation, ":"),
+            (TokenKind::Word, "ota-ona"),
+            (TokenKind::Number, "3,5"),
+            (TokenKind::Word, "kg"),
+            (TokenKind::Punctuation, ";"),
+            (TokenKind::Number, "2023"),
+            (TokenKind::Punctuation, "-"),
+            (TokenKind::Word, "yil"),
+            (TokenKind::Punctuation, "\""),
+            (TokenKind::Word, "salom"),
+            (TokenKind::Punctuation, "\""),
+        ];
+
+        let expected: Vec<(TokenKind, String)> = expected
+            .into_iter()
+            .map(|(kind, text)| (kind, text.to_string()))
+            .collect();
+        assert_eq!(kinds(text), expected);
+
+        // cyrillic text and enclitics
+        let words: Vec<String> = kinds("Ўзбекистон-ку, борасан-ми? Маъно - тил")
+            .into_iter()
+            .filter(|(kind, _)| *kind == TokenKind::Word)
+            .map(|(_, text)| text)
+            .collect();
+        assert_eq!(words, vec!["Ўзбекистон-ку", "борасан-ми", "Маъно", "тил"]);
+    }
+
+    #[test]
+    fn special_test() {
+        let text = "Yozing: nyan@mail.uz yoki https://korrektor.uz/api, @crystalny";
+        let special: Vec<(TokenKind, String)> = kinds(text)
+            .into_iter()
+            .filter(|(kind, _)| {
+                matches!(
+                    kind,
+                    TokenKind::Email | TokenKind::Url | TokenKind::Username
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            special,
+            vec![
+                (TokenKind::Email, String::from("nyan@mail.uz")),
+                (TokenKind::Url, String::from("https://korrektor.uz/api")),
+                (TokenKind::Username, String::from("@crystalny")),
+            ]
+        );
+    }
+
+    #[test]
+    fn spans_test() {
+        let text = "  Salom,\n\tdunyo!  12.5 https://nyan.com ʼ";
+        let tokens = words(text);
+
+        let mut end = 0;
+        for token in &tokens {
+            assert_eq!(token.span.start, end);
+            assert_eq!(&text[token.span.clone()], token.text);
+            end = token.span.end;
+        }
+        assert_eq!(end, text.len());
+
+        assert!(words("").is_empty());
+    }
+}
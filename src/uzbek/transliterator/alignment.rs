@@ -0,0 +1,314 @@
+//! Tracking of byte ranges through the transliteration pipeline.
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// Part of the source text and the part of the output it was transliterated into.
+///
+/// Both ranges are byte ranges. Either of them may be empty: an empty
+/// `source` marks inserted text, an empty `target` marks removed text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Alignment {
+    pub source: Range<usize>,
+    pub target: Range<usize>,
+}
+
+/// Transliterated text together with its alignment to the source text.
+///
+/// Alignments are sorted, do not overlap and cover both texts
+/// completely. Characters left intact by every rule keep an alignment
+/// of their own, text rewritten by a rule is aligned as a whole.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::transliterator;
+/// use korrektor::uzbek::Alphabet;
+///
+/// let aligned = transliterator::to_aligned(String::from("shahar"), Alphabet::Cyrillic);
+/// assert_eq!(aligned.text, "шаҳар");
+///
+/// // "sh" became "ш", "h" became "ҳ"
+/// assert_eq!(aligned.to_target(0..2), 0..2);
+/// assert_eq!(aligned.to_target(3..4), 4..6);
+/// assert_eq!(aligned.to_source(4..6), 3..4);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlignedText {
+    pub text: String,
+    pub alignment: Vec<Alignment>,
+}
+
+impl AlignedText {
+    /// Maps a byte range of the source text to the byte range of the output.
+    ///
+    /// The result covers every alignment the range touches, so a
+    /// range inside a rewritten part maps to the whole rewritten part.
+    /// An empty range (a cursor position) maps to an empty range,
+    /// unless it points inside of a rewritten part.
+    pub fn to_target(&self, source: Range<usize>) -> Range<usize> {
+        map_range(&self.alignment, source, |a| (&a.source, &a.target))
+    }
+
+    /// Maps a byte range of the output to the byte range of the source text.
+    ///
+    /// The inverse of [`AlignedText::to_target`].
+    pub fn to_source(&self, target: Range<usize>) -> Range<usize> {
+        map_range(&self.alignment, target, |a| (&a.target, &a.source))
+    }
+}
+
+fn map_range(
+    alignment: &[Alignment],
+    range: Range<usize>,
+    sides: impl Fn(&Alignment) -> (&Range<usize>, &Range<usize>),
+) -> Range<usize> {
+    let mut result: Option<Range<usize>> = None;
+
+    for (from, to) in alignment.iter().map(&sides) {
+        if overlaps(from, &range) {
+            result = Some(match result {
+                Some(mapped) => mapped.start..to.end,
+                None => to.clone(),
+            });
+        }
+    }
+
+    result.unwrap_or_else(|| {
+        // position between two alignments
+        let position = alignment
+            .iter()
+            .map(&sides)
+            .find(|(from, _)| from.start >= range.start)
+            .map(|(_, to)| to.start)
+            .or_else(|| alignment.last().map(|a| sides(a).1.end))
+            .unwrap_or(0);
+
+        position..position
+    })
+}
+
+// an empty range overlaps only with ranges strictly containing it
+fn overlaps(range: &Range<usize>, other: &Range<usize>) -> bool {
+    if other.is_empty() {
+        range.start < other.start && range.end > other.start
+    } else {
+        range.start < other.end && range.end > other.start
+    }
+}
+
+/// Replacement of a byte range of the text.
+pub(super) type Edit = (Range<usize>, String);
+
+/// Text going through the transliteration pipeline.
+///
+/// Keeps track of the alignment to the source text only if asked to,
+/// so plain transliteration does not pay for it.
+pub(super) struct TrackedText {
+    text: String,
+    alignment: Option<Vec<Alignment>>,
+}
+
+impl TrackedText {
+    pub(super) fn new(text: &str) -> Self {
+        TrackedText {
+            text: text.to_string(),
+            alignment: None,
+        }
+    }
+
+    pub(super) fn aligned(text: &str) -> Self {
+        let alignment = text
+            .char_indices()
+            .map(|(index, char)| {
+                let range = index..index + char.len_utf8();
+                Alignment {
+                    source: range.clone(),
+                    target: range,
+                }
+            })
+            .collect();
+
+        TrackedText {
+            text: text.to_string(),
+            alignment: Some(alignment),
+        }
+    }
+
+    pub(super) fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub(super) fn is_aligned(&self) -> bool {
+        self.alignment.is_some()
+    }
+
+    /// Replaces the whole text, only for a text with no alignment to keep.
+    pub(super) fn set(&mut self, text: String) {
+        debug_assert!(self.alignment.is_none());
+        self.text = text;
+    }
+
+    /// Applies sorted, non-overlapping edits to the text.
+    pub(super) fn edit(&mut self, edits: Vec<Edit>) {
+        // rules often match text they leave as it is
+        let edits: Vec<Edit> = edits
+            .into_iter()
+            .filter(|(range, replacement)| self.text[range.clone()] != *replacement)
+            .collect();
+
+        if edits.is_empty() {
+            return;
+        }
+
+        let mut text = String::with_capacity(self.text.len());
+        let mut last = 0;
+        for (range, replacement) in &edits {
+            text.push_str(&self.text[last..range.start]);
+            text.push_str(replacement);
+            last = range.end;
+        }
+        text.push_str(&self.text[last..]);
+
+        if let Some(alignment) = self.alignment.take() {
+            self.alignment = Some(realign(alignment, &edits));
+        }
+        self.text = text;
+    }
+
+    pub(super) fn into_string(self) -> String {
+        self.text
+    }
+
+    pub(super) fn into_aligned(self) -> AlignedText {
+        let mut alignment = self.alignment.unwrap_or_default();
+        // text inserted and removed again (like the 〈〉 wrapping) maps nothing to nothing
+        alignment.retain(|a| !(a.source.is_empty() && a.target.is_empty()));
+
+        AlignedText {
+            text: self.text,
+            alignment,
+        }
+    }
+}
+
+/// Moves the target side of the alignment through the edits,
+/// alignments touched by an edit are merged into one.
+fn realign(alignment: Vec<Alignment>, edits: &[Edit]) -> Vec<Alignment> {
+    let mut result: Vec<Alignment> = Vec::with_capacity(alignment.len());
+    let mut alignment = alignment.into_iter().peekable();
+    // difference between positions in the new and the old text
+    let mut shift: isize = 0;
+
+    let moved = |position: usize, shift: isize| (position as isize + shift) as usize;
+
+    for (range, replacement) in edits {
+        // untouched alignments before the edit
+        while let Some(next) = alignment.next_if(|a| a.target.end <= range.start) {
+            result.push(Alignment {
+                source: next.source,
+                target: moved(next.target.start, shift)..moved(next.target.end, shift),
+            });
+        }
+
+        let mut merged: Option<Alignment> = None;
+        while let Some(next) = alignment.next_if(|a| overlaps(&a.target, range)) {
+            merged = Some(match merged {
+                Some(merged) => Alignment {
+                    source: merged.source.start..next.source.end,
+                    target: merged.target.start..next.target.end,
+                },
+                None => next,
+            });
+        }
+
+        let delta = replacement.len() as isize - range.len() as isize;
+        match merged {
+            Some(merged) => {
+                let start = merged.target.start.min(range.start);
+                let end = merged.target.end.max(range.end);
+
+                result.push(Alignment {
+                    source: merged.source,
+                    target: moved(start, shift)..moved(end, shift + delta),
+                });
+            }
+            None => {
+                // insertion between two alignments
+                let source = result.last().map_or(0, |a| a.source.end);
+                let start = moved(range.start, shift);
+
+                result.push(Alignment {
+                    source: source..source,
+                    target: start..start + replacement.len(),
+                });
+            }
+        }
+
+        shift += delta;
+    }
+
+    result.extend(alignment.map(|next| Alignment {
+        source: next.source,
+        target: moved(next.target.start, shift)..moved(next.target.end, shift),
+    }));
+
+    result
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    fn assert_covers(aligned: &AlignedText, source: &str) {
+        let mut source_end = 0;
+        let mut target_end = 0;
+
+        for a in &aligned.alignment {
+            assert_eq!(a.source.start, source_end, "{aligned:?}");
+            assert_eq!(a.target.start, target_end, "{aligned:?}");
+            source_end = a.source.end;
+            target_end = a.target.end;
+        }
+
+        assert_eq!(source_end, source.len());
+        assert_eq!(target_end, aligned.text.len());
+    }
+
+    #[test]
+    fn edit_test() {
+        let source = "abcdef";
+        let mut text = TrackedText::aligned(source);
+
+        // replace, remove and insert
+        text.edit(vec![
+            (0..2, String::from("X")),
+            (3..4, String::new()),
+            (5..5, String::from("YY")),
+        ]);
+        assert_eq!(text.as_str(), "XceYYf");
+
+        text.edit(vec![(1..3, String::from("ZZZ"))]);
+        let aligned = text.into_aligned();
+
+        assert_eq!(aligned.text, "XZZZYYf");
+        assert_covers(&aligned, source);
+
+        assert_eq!(aligned.to_target(0..1), 0..1);
+        assert_eq!(aligned.to_target(2..3), 1..4);
+        assert_eq!(aligned.to_target(5..6), 6..7);
+        assert_eq!(aligned.to_source(6..7), 5..6);
+        assert_eq!(aligned.to_source(0..1), 0..2);
+
+        // cursor positions
+        assert_eq!(aligned.to_target(2..2), 1..1);
+        assert_eq!(aligned.to_target(6..6), 7..7);
+        assert_eq!(aligned.to_source(1..1), 2..2);
+    }
+
+    #[test]
+    fn untracked_edit_test() {
+        let mut text = TrackedText::new("abc");
+        text.edit(vec![(1..2, String::from("bb")), (3..3, String::from("d"))]);
+
+        assert_eq!(text.into_string(), "abbcd");
+    }
+}
@@ -0,0 +1,251 @@
+//! User-supplied exception dictionaries for transliteration.
+use crate::error::KorrektorError;
+use crate::uzbek::Alphabet;
+use serde::{Deserialize, Serialize};
+
+/// Word written in both alphabets.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DictionaryEntry {
+    pub latin: String,
+    pub cyrillic: String,
+    /// Entries with higher priority are tried first, 0 by default.
+    #[serde(default)]
+    pub priority: i32,
+}
+
+/// Words (brand names, place names, surnames, ...) transliterated
+/// the given way instead of following the rules.
+///
+/// Like the built-in loanword tables, an entry matches from the beginning
+/// of a word, so suffixes (`Uzumda`) are transliterated by the rules.
+/// Every entry is also matched in lower, UPPER and Title case.
+/// Dictionary entries are tried before the built-in tables, entries
+/// with higher priority before the ones with lower priority.
+/// Apostrophes in the words are normalized like in the rest of the text.
+///
+/// TSV dictionaries hold `latin<TAB>cyrillic[<TAB>priority]` lines, empty
+/// lines and lines starting with `#` are skipped. JSON dictionaries are
+/// arrays of `{"latin": "...", "cyrillic": "...", "priority": 0}` objects.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::transliterator::{TransliterationDictionary, Transliterator};
+///
+/// let dictionary = TransliterationDictionary::from_tsv("Uzum\tУзум\nBeeline\tБилайн\t1").unwrap();
+/// let transliterator = Transliterator::with_dictionary(&dictionary);
+///
+/// assert_eq!(transliterator.to_cyrillic("BEELINE va Uzumda"), "БИЛАЙН ва Узумда");
+/// assert_eq!(transliterator.to_latin("Билайн"), "Beeline");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TransliterationDictionary {
+    entries: Vec<DictionaryEntry>,
+}
+
+impl TransliterationDictionary {
+    /// Creates an empty dictionary.
+    pub fn new() -> Self {
+        TransliterationDictionary::default()
+    }
+
+    /// Parses a dictionary from tab separated lines.
+    ///
+    /// # Errors
+    /// Returns [`KorrektorError::InvalidDictionary`] with the line number
+    /// if a line does not have two non-empty columns or the priority is not a number.
+    pub fn from_tsv(text: &str) -> Result<Self, KorrektorError> {
+        let mut dictionary = TransliterationDictionary::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid =
+                |message: &str| KorrektorError::InvalidDictionary(index + 1, message.to_string());
+            let columns: Vec<&str> = line.split('\t').map(str::trim).collect();
+
+            let (latin, cyrillic, priority) = match columns[..] {
+                [latin, cyrillic] => (latin, cyrillic, 0),
+                [latin, cyrillic, priority] => {
+                    let priority = priority
+                        .parse()
+                        .map_err(|_| invalid("priority is not a number"))?;
+                    (latin, cyrillic, priority)
+                }
+                _ => {
+                    return Err(invalid(
+                        "expected latin, cyrillic and optional priority separated by tabs",
+                    ))
+                }
+            };
+
+            if latin.is_empty() || cyrillic.is_empty() {
+                return Err(invalid("empty word"));
+            }
+
+            dictionary.insert(latin, cyrillic, priority);
+        }
+
+        Ok(dictionary)
+    }
+
+    /// Parses a dictionary from a JSON array of entries.
+    ///
+    /// # Errors
+    /// Returns [`KorrektorError::InvalidDictionary`] if the JSON is malformed
+    /// (with the line number) or an entry has an empty word (with line 0).
+    pub fn from_json(text: &str) -> Result<Self, KorrektorError> {
+        let dictionary: TransliterationDictionary = serde_json::from_str(text)
+            .map_err(|error| KorrektorError::InvalidDictionary(error.line(), error.to_string()))?;
+
+        if let Some(position) = dictionary
+            .entries
+            .iter()
+            .position(|entry| entry.latin.is_empty() || entry.cyrillic.is_empty())
+        {
+            return Err(KorrektorError::InvalidDictionary(
+                0,
+                format!("empty word in entry {}", position + 1),
+            ));
+        }
+
+        Ok(dictionary)
+    }
+
+    /// Adds an entry to the dictionary.
+    pub fn insert(&mut self, latin: &str, cyrillic: &str, priority: i32) {
+        self.entries.push(DictionaryEntry {
+            latin: latin.to_string(),
+            cyrillic: cyrillic.to_string(),
+            priority,
+        });
+    }
+
+    /// Adds all entries of another dictionary.
+    pub fn merge(&mut self, other: TransliterationDictionary) {
+        self.entries.extend(other.entries);
+    }
+
+    pub fn entries(&self) -> &[DictionaryEntry] {
+        &self.entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Pairs of words to find and replacements for transliteration into the
+    /// given alphabet, in every case and in the order they should be tried.
+    pub(super) fn pairs(&self, alphabet: Alphabet) -> Vec<(String, String)> {
+        let mut entries: Vec<&DictionaryEntry> = self.entries.iter().collect();
+        // stable, entries added first win on equal priority
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.priority));
+
+        let mut pairs: Vec<(String, String)> = vec![];
+        for entry in entries {
+            let (from, to) = match alphabet {
+                Alphabet::Cyrillic => (&entry.latin, &entry.cyrillic),
+                Alphabet::Latin => (&entry.cyrillic, &entry.latin),
+            };
+
+            let variants = [
+                (from.clone(), to.clone()),
+                (from.to_lowercase(), to.to_lowercase()),
+                (from.to_uppercase(), to.to_uppercase()),
+                (capitalize(from), capitalize(to)),
+            ];
+
+            for (from, to) in variants {
+                if !pairs.iter().any(|(pattern, _)| *pattern == from) {
+                    pairs.push((from, to));
+                }
+            }
+        }
+
+        pairs
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    #[test]
+    fn from_tsv_test() {
+        let dictionary =
+            TransliterationDictionary::from_tsv("# brendlar\nUzum\tУзум\n\nBeeline\tБилайн\t5\r\n")
+                .unwrap();
+
+        assert_eq!(dictionary.entries().len(), 2);
+        assert_eq!(dictionary.entries()[1].cyrillic, "Билайн");
+        assert_eq!(dictionary.entries()[1].priority, 5);
+
+        for (tsv, line) in [("Uzum", 1), ("a\tа\n\tб", 2), ("a\tа\tx", 1)] {
+            match TransliterationDictionary::from_tsv(tsv) {
+                Err(KorrektorError::InvalidDictionary(error_line, _)) => {
+                    assert_eq!(error_line, line)
+                }
+                other => panic!("expected an error for {tsv:?}, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn from_json_test() {
+        let dictionary = TransliterationDictionary::from_json(
+            r#"[{"latin": "Uzum", "cyrillic": "Узум"}, {"latin": "Beeline", "cyrillic": "Билайн", "priority": 5}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(dictionary.entries()[0].priority, 0);
+        assert_eq!(dictionary.entries()[1].priority, 5);
+        assert_eq!(
+            serde_json::from_str::<TransliterationDictionary>(
+                &serde_json::to_string(&dictionary).unwrap()
+            )
+            .unwrap(),
+            dictionary
+        );
+
+        assert!(TransliterationDictionary::from_json("[{\"latin\": \"Uzum\"}]").is_err());
+        assert!(TransliterationDictionary::from_json(
+            "[{\"latin\": \"\", \"cyrillic\": \"Узум\"}]"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn pairs_test() {
+        let mut dictionary = TransliterationDictionary::new();
+        dictionary.insert("iPhone", "айфон", 0);
+        dictionary.insert("Uzum", "Узум", 1);
+
+        let pairs = dictionary.pairs(Alphabet::Cyrillic);
+        let patterns: Vec<&str> = pairs.iter().map(|(pattern, _)| pattern.as_str()).collect();
+
+        // higher priority first, every case once
+        assert_eq!(
+            patterns,
+            vec!["Uzum", "uzum", "UZUM", "iPhone", "iphone", "IPHONE", "Iphone"]
+        );
+        assert_eq!(pairs[6].1, "Айфон");
+
+        let pairs = dictionary.pairs(Alphabet::Latin);
+        assert_eq!(pairs[0], (String::from("Узум"), String::from("Uzum")));
+    }
+}
@@ -1,101 +1,487 @@
+//! Functions to transliterate Uzbek text between latin and cyrillic alphabets.
+//!
+//! All rule tables are compiled once into a [`Transliterator`],
+//! free functions of this module share a single lazily built instance.
+//!
+//! The `*_aligned` variants additionally return an alignment of the
+//! output to the source text, see [`AlignedText`].
+mod alignment;
+mod dictionary;
 pub(crate) mod prelude;
-use crate::utils::pcre as ipcre;
+mod round_trip;
 use crate::utils::wrappers;
-use inflector::Inflector;
+use alignment::{Edit, TrackedText};
+use crate::uzbek::Alphabet;
+use aho_corasick::{AhoCorasick, Input, MatchKind};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::borrow::Cow;
+
+pub use alignment::{AlignedText, Alignment};
+pub use dictionary::{DictionaryEntry, TransliterationDictionary};
+pub use round_trip::{RoundTripDifference, RoundTripIssue, RoundTripReport};
+
+static TRANSLITERATOR: Lazy<Transliterator> = Lazy::new(Transliterator::new);
+
+/// Transliteration engine with precompiled rule tables.
+///
+/// Building the engine compiles every rule table of the module,
+/// so create it once and reuse it. The engine is `Send + Sync`
+/// and can be shared between threads behind a reference or an `Arc`.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::transliterator::Transliterator;
+/// use korrektor::uzbek::Alphabet;
+///
+/// let transliterator = Transliterator::new();
+///
+/// assert_eq!(transliterator.to_cyrillic("g'ozal"), "ғозал");
+/// assert_eq!(transliterator.to_latin("ғозал"), "g‘ozal");
+/// assert_eq!(transliterator.to("g'ozal", Alphabet::Cyrillic), "ғозал");
+/// ```
+pub struct Transliterator {
+    to_cyr: Rules,
+    closing_quote: Regex,
+    latin_dictionary: Option<Retransliteration>,
+    pre_retranslit: Rules,
+    retranslit: Retransliteration,
+    post_retranslit: Rules,
+    to_cyr_sub: Rules,
+    latin_to_cyrillic: Rules,
+    to_cyr_correct: Rules,
+    cyrillic_dictionary: Option<Retransliteration>,
+    pre_to_latin: Rules,
+    cyrillic_to_latin: Rules,
+    to_latin: Rules,
+    unwrap: Regex,
+}
 
-pub fn to_cyrillic(text: String) -> String {
-    let mut input = text;
+impl Transliterator {
+    /// Compiles all transliteration rule tables.
+    pub fn new() -> Self {
+        Transliterator {
+            to_cyr: Rules::new(prelude::TO_CYR),
+            closing_quote: Regex::new("([a-zA-Zа-яА-ЯўқғҳЎҚҒҲʼʻ?!.0-9])(“|”|\")").unwrap(),
+            latin_dictionary: None,
+            pre_retranslit: Rules::new(prelude::PRE_RETRANSLIT),
+            retranslit: Retransliteration::new(prelude::RETRANSLIT),
+            post_retranslit: Rules::new(prelude::POST_RETRANSLIT),
+            to_cyr_sub: Rules::new(prelude::TO_CYR_SUB),
+            latin_to_cyrillic: Rules::letters(prelude::LATIN_L2C, prelude::CYRILLIC_L2C),
+            to_cyr_correct: Rules::new(prelude::TO_CYR_CORRECT),
+            cyrillic_dictionary: None,
+            pre_to_latin: Rules::new(prelude::PRE_TO_LATIN),
+            cyrillic_to_latin: Rules::letters(prelude::CYRILLIC_C2L, prelude::LATIN_C2L),
+            to_latin: Rules::new(prelude::TO_LATIN),
+            unwrap: Regex::new("[〈〉]").unwrap(),
+        }
+    }
+
+    /// Compiles all transliteration rule tables along with
+    /// the words of the dictionary, see [`TransliterationDictionary`].
+    pub fn with_dictionary(dictionary: &TransliterationDictionary) -> Self {
+        let mut transliterator = Transliterator::new();
+        if dictionary.is_empty() {
+            return transliterator;
+        }
+
+        // latin words are looked up after apostrophes and o‘, g‘ are normalized
+        let latin = dictionary
+            .pairs(Alphabet::Cyrillic)
+            .into_iter()
+            .map(|(pattern, replacement)| {
+                let mut pattern = TrackedText::new(&pattern);
+                transliterator.to_cyr.apply(&mut pattern);
+
+                (pattern.into_string(), replacement)
+            })
+            .collect();
+
+        transliterator.latin_dictionary = Some(Retransliteration::from_pairs(latin));
+        transliterator.cyrillic_dictionary = Some(Retransliteration::from_pairs(
+            dictionary.pairs(Alphabet::Latin),
+        ));
+
+        transliterator
+    }
+
+    /// Transliterates latin text into cyrillic.
+    pub fn to_cyrillic(&self, text: &str) -> String {
+        let mut text = TrackedText::new(text);
+        self.latin_to_cyrillic_text(&mut text);
+
+        text.into_string()
+    }
+
+    /// Transliterates latin text into cyrillic, keeping track of the alignment.
+    pub fn to_cyrillic_aligned(&self, text: &str) -> AlignedText {
+        let mut text = TrackedText::aligned(text);
+        self.latin_to_cyrillic_text(&mut text);
+
+        text.into_aligned()
+    }
+
+    /// Transliterates cyrillic text into latin.
+    pub fn to_latin(&self, text: &str) -> String {
+        let mut text = TrackedText::new(text);
+        self.cyrillic_to_latin_text(&mut text);
+
+        text.into_string()
+    }
+
+    /// Transliterates cyrillic text into latin, keeping track of the alignment.
+    pub fn to_latin_aligned(&self, text: &str) -> AlignedText {
+        let mut text = TrackedText::aligned(text);
+        self.cyrillic_to_latin_text(&mut text);
+
+        text.into_aligned()
+    }
+
+    /// Transliterates text into the given alphabet.
+    pub fn to(&self, text: &str, alphabet: Alphabet) -> String {
+        let mut text = TrackedText::new(text);
+        self.to_text(&mut text, alphabet);
+
+        text.into_string()
+    }
+
+    /// Transliterates text into the given alphabet, keeping track of the alignment.
+    ///
+    /// # Example
+    /// ```rust
+    /// use korrektor::uzbek::transliterator::Transliterator;
+    /// use korrektor::uzbek::Alphabet;
+    ///
+    /// let transliterator = Transliterator::new();
+    /// let aligned = transliterator.to_aligned("salom, dunyo", Alphabet::Cyrillic);
+    ///
+    /// assert_eq!(aligned.text, "салом, дунё");
+    /// // "dunyo" in the output
+    /// assert_eq!(aligned.to_target(7..12), 12..20);
+    /// ```
+    pub fn to_aligned(&self, text: &str, alphabet: Alphabet) -> AlignedText {
+        let mut text = TrackedText::aligned(text);
+        self.to_text(&mut text, alphabet);
+
+        text.into_aligned()
+    }
+
+    fn latin_to_cyrillic_text(&self, text: &mut TrackedText) {
+        self.to_cyr.apply(text);
+
+        replace_all(text, &self.closing_quote, "$1»");
+
+        if let Some(dictionary) = &self.latin_dictionary {
+            dictionary.apply(text);
+        }
 
-    input = ipcre::replace_pairs(&input, prelude::TO_CYR);
+        self.pre_retranslit.apply(text);
 
-    let re = regex::Regex::new("([a-zA-Zа-яА-ЯўқғҳЎҚҒҲʼʻ?!.0-9])(“|”|\")").unwrap();
-    input = re.replace_all(&input, "$1»").as_ref().to_string();
+        // replace constant words from constants::RETRANSLIT
+        self.retranslit.apply(text);
 
-    input = ipcre::replace_pairs(&input, prelude::PRE_RETRANSLIT);
+        self.post_retranslit.apply(text);
+        self.to_cyr_sub.apply(text);
+        self.latin_to_cyrillic.apply(text);
+        self.to_cyr_correct.apply(text);
+    }
+
+    fn cyrillic_to_latin_text(&self, text: &mut TrackedText) {
+        if let Some(dictionary) = &self.cyrillic_dictionary {
+            dictionary.apply(text);
+        }
+
+        self.pre_to_latin.apply(text);
+        self.cyrillic_to_latin.apply(text);
+        self.to_latin.apply(text);
+    }
 
-    // replace constant words from constants::RETRANSLIT
-    input = retransliterate(&input);
+    fn to_text(&self, text: &mut TrackedText, alphabet: Alphabet) {
+        // wrapping special text with 〈〉 to avoid correction and transliteration (emails, usernames, URLs)
+        let wrapped = wrappers::get_wrapped_text(text.as_str());
+        let edits = insertions(text.as_str(), &wrapped);
+        text.edit(edits);
 
-    input = ipcre::replace_pairs(&input, prelude::POST_RETRANSLIT);
-    input = ipcre::replace_pairs(&input, prelude::TO_CYR_SUB);
-    input = replace_letters(&input, prelude::LATIN_L2C, prelude::CYRILLIC_L2C);
-    input = ipcre::replace_pairs(&input, prelude::TO_CYR_CORRECT);
+        // transliterate to specified language passed as argument
+        match alphabet {
+            Alphabet::Cyrillic => self.latin_to_cyrillic_text(text),
+            Alphabet::Latin => self.cyrillic_to_latin_text(text),
+        };
 
-    input
+        // unwrap special text
+        replace_all(text, &self.unwrap, "");
+    }
 }
 
-pub fn to_latin(text: String) -> String {
-    let mut input = text;
+impl Default for Transliterator {
+    fn default() -> Self {
+        Transliterator::new()
+    }
+}
+
+/// Transliterates latin text into cyrillic.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::transliterator;
+///
+/// let output = transliterator::to_cyrillic(String::from("G'ozal geliy"));
+/// assert_eq!(output, "Ғозал гелий".to_string());
+/// ```
+pub fn to_cyrillic(text: String) -> String {
+    TRANSLITERATOR.to_cyrillic(&text)
+}
 
-    input = ipcre::replace_pairs(&input, prelude::PRE_TO_LATIN);
+/// Transliterates cyrillic text into latin.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::transliterator;
+///
+/// let output = transliterator::to_latin(String::from("Ғозал гелий"));
+/// assert_eq!(output, "Gʼozal geliy".to_string());
+/// ```
+pub fn to_latin(text: String) -> String {
+    TRANSLITERATOR.to_latin(&text)
+}
 
-    input = replace_letters(&input, prelude::CYRILLIC_C2L, prelude::LATIN_C2L);
+/// Transliterates text into the given alphabet.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::{transliterator, Alphabet};
+///
+/// let output = transliterator::to(String::from("g'ozal"), Alphabet::Cyrillic);
+/// assert_eq!(output, "ғозал".to_string());
+/// ```
+pub fn to(text: String, alphabet: Alphabet) -> String {
+    TRANSLITERATOR.to(&text, alphabet)
+}
 
-    input = ipcre::replace_pairs(&input, prelude::TO_LATIN);
+/// Transliterates text into the given alphabet, keeping track of
+/// which part of the source text every part of the output came from.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::{transliterator, Alphabet};
+///
+/// let aligned = transliterator::to_aligned(String::from("Ғозал шаҳар"), Alphabet::Latin);
+/// assert_eq!(aligned.text, "Gʼozal shahar");
+///
+/// // "шаҳар" in the output
+/// assert_eq!(aligned.to_target(11..21), 8..14);
+/// // a cursor right before "шаҳар"
+/// assert_eq!(aligned.to_target(11..11), 8..8);
+/// ```
+pub fn to_aligned(text: String, alphabet: Alphabet) -> AlignedText {
+    TRANSLITERATOR.to_aligned(&text, alphabet)
+}
 
-    input
+/// Transliterates text written in the given alphabet into the
+/// other one and back, reporting every word that did not survive.
+///
+/// # Example
+/// ```rust
+/// use korrektor::uzbek::transliterator::{self, RoundTripIssue};
+/// use korrektor::uzbek::Alphabet;
+///
+/// let report = transliterator::round_trip_report("G'OZAL shahar", Alphabet::Latin);
+/// assert_eq!(report.differences[0].round_trip, "GʼOZAL");
+/// assert_eq!(report.differences[0].issue, RoundTripIssue::Apostrophe);
+///
+/// let report = transliterator::round_trip_report("Гоголь куни", Alphabet::Cyrillic);
+/// assert_eq!(report.differences[0].word, "Гоголь");
+/// assert_eq!(report.differences[0].issue, RoundTripIssue::SoftSign);
+/// ```
+pub fn round_trip_report(text: &str, alphabet: Alphabet) -> RoundTripReport {
+    TRANSLITERATOR.round_trip_report(text, alphabet)
 }
 
-pub fn to(text: String, alphabet: &str) -> String {
-    // wrapping special text with 〈〉 to avoid correction and transliteration (emails, usernames, URLs)
-    let mut input = wrappers::get_wrapped_text(&text);
+/// Replaces matches of the regex, expanding `$n` references in the replacement.
+///
+/// Edits are collected only if the alignment is tracked, otherwise the text
+/// is replaced in one go.
+fn replace_all(text: &mut TrackedText, re: &Regex, replacement: &str) {
+    if text.is_aligned() {
+        let edits = regex_edits(re, replacement, text.as_str());
+        text.edit(edits);
+    } else if let Cow::Owned(replaced) = re.replace_all(text.as_str(), replacement) {
+        text.set(replaced);
+    }
+}
 
-    // transliterate to specified language passed as argument
-    match alphabet {
-        "cyr" => input = to_cyrillic(input),
-        "lat" => input = to_latin(input),
-        _ => input = to_latin(input),
+/// Edits matches of the regex, expanding `$n` references in the replacement.
+fn regex_edits(re: &Regex, replacement: &str, input: &str) -> Vec<Edit> {
+    if !replacement.contains('$') {
+        return re
+            .find_iter(input)
+            .map(|found| (found.range(), replacement.to_string()))
+            .collect();
     }
 
-    // unwrap special text
-    let re = regex::Regex::new("[〈〉]").unwrap();
-    input = re.replace_all(&input, "").to_string();
+    re.captures_iter(input)
+        .map(|captures| {
+            let mut expanded = String::new();
+            captures.expand(replacement, &mut expanded);
+
+            (captures.get(0).unwrap().range(), expanded)
+        })
+        .collect()
+}
+
+/// Edits inserting the characters `wrapped` has on top of `text`.
+fn insertions(text: &str, wrapped: &str) -> Vec<Edit> {
+    let mut edits: Vec<Edit> = vec![];
+    let mut chars = text.char_indices().peekable();
+
+    for char in wrapped.chars() {
+        match chars.next_if(|(_, next)| *next == char) {
+            Some(_) => {}
+            None => {
+                let index = chars.peek().map_or(text.len(), |(index, _)| *index);
+                edits.push((index..index, char.to_string()));
+            }
+        }
+    }
 
-    input
+    edits
 }
 
-fn replace_letters(input: &str, input_alphabet: &[&str], output_alphabet: &[&str]) -> String {
-    let mut input = input.to_string();
+/// Regex rules applied one after another over the whole text.
+struct Rules(Vec<(Regex, &'static str)>);
+
+impl Rules {
+    fn new(pairs: &[(&'static str, &'static str)]) -> Self {
+        Rules(
+            pairs
+                .iter()
+                .map(|(pattern, replacement)| (Regex::new(pattern).unwrap(), *replacement))
+                .collect(),
+        )
+    }
+
+    fn letters(input_alphabet: &[&'static str], output_alphabet: &[&'static str]) -> Self {
+        let pairs: Vec<(&str, &str)> = input_alphabet
+            .iter()
+            .copied()
+            .zip(output_alphabet.iter().copied())
+            .collect();
+
+        Rules::new(&pairs)
+    }
 
-    let mut i = 0;
-    while i < input_alphabet.len() {
-        let re = regex::Regex::new(input_alphabet[i]).unwrap();
-        input = re
-            .replace_all(&input, output_alphabet[i])
-            .as_ref()
-            .to_string();
-        i += 1;
+    fn apply(&self, text: &mut TrackedText) {
+        for (re, replacement) in &self.0 {
+            replace_all(text, re, replacement);
+        }
     }
+}
 
-    input
+/// Whole word (prefix) replacements from constants::RETRANSLIT
+/// or a [`TransliterationDictionary`].
+///
+/// All words are searched in a single pass, earlier entries of
+/// the table win over later ones starting at the same position.
+struct Retransliteration {
+    matcher: AhoCorasick,
+    patterns: Vec<String>,
+    replacements: Vec<String>,
+    /// Whether words are matched in any case, the replacement taking the case
+    /// of the matched word, or only the way they are written.
+    any_case: bool,
 }
 
-fn retransliterate(input: &str) -> String {
-    let mut input = input.to_string();
+impl Retransliteration {
+    fn new(pairs: &[(&str, &str)]) -> Self {
+        let pairs = pairs
+            .iter()
+            .map(|(pattern, replacement)| (pattern.to_string(), replacement.to_string()))
+            .collect();
+
+        Retransliteration::build(pairs, true)
+    }
+
+    fn from_pairs(pairs: Vec<(String, String)>) -> Self {
+        Retransliteration::build(pairs, false)
+    }
+
+    fn build(pairs: Vec<(String, String)>, any_case: bool) -> Self {
+        let (patterns, replacements): (Vec<String>, Vec<String>) = pairs.into_iter().unzip();
+
+        let matcher = AhoCorasick::builder()
+            .match_kind(MatchKind::LeftmostFirst)
+            .ascii_case_insensitive(any_case)
+            .build(&patterns)
+            .unwrap();
+
+        Retransliteration {
+            matcher,
+            patterns,
+            replacements,
+            any_case,
+        }
+    }
+
+    // replacement of the matched word in its case: as written in the table when
+    // the word is, otherwise lower, UPPER or Title, other mixed case is not replaced
+    fn replacement(&self, index: usize, word: &str) -> Option<String> {
+        let replacement = &self.replacements[index];
+        if !self.any_case || word == self.patterns[index] {
+            return Some(replacement.clone());
+        }
 
-    for (pattern, replacement) in prelude::RETRANSLIT {
-        let wrapped = String::from("\\b") + pattern;
+        let mut chars = word.chars();
+        let first = chars.next()?;
+        if word == word.to_lowercase() {
+            Some(replacement.to_lowercase())
+        } else if word == word.to_uppercase() {
+            Some(replacement.to_uppercase())
+        } else if first.is_uppercase() && chars.as_str() == chars.as_str().to_lowercase() {
+            let lowercase = replacement.to_lowercase();
+            let mut chars = lowercase.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+        } else {
+            None
+        }
+    }
 
-        let text = input.clone();
-        let re = regex::Regex::new(&wrapped).unwrap();
-        for cap in re.captures_iter(&text) {
-            if cap[0] == cap[0].to_string().to_lowercase() {
-                input = re.replace(&input, *replacement).parse().unwrap();
-            } else if cap[0] == cap[0].to_string().to_uppercase() {
-                input = re
-                    .replace(&input, replacement.to_uppercase())
-                    .parse()
-                    .unwrap();
-            } else if cap[0] == cap[0].to_title_case() {
-                input = re
-                    .replace(&input, replacement.to_title_case())
-                    .parse()
-                    .unwrap();
+    fn apply(&self, text: &mut TrackedText) {
+        let input = text.as_str();
+        let mut edits: Vec<Edit> = vec![];
+        let mut at = 0;
+
+        while let Some(found) = self.matcher.find(Input::new(input).range(at..)) {
+            let replacement = if is_word_start(input, found.start()) {
+                self.replacement(found.pattern().as_usize(), &input[found.range()])
+            } else {
+                None
+            };
+
+            if let Some(replacement) = replacement {
+                edits.push((found.range(), replacement));
+                at = found.end();
+            } else {
+                // words are matched only from their beginning (\b) and in a known case
+                let skip = input[found.start()..]
+                    .chars()
+                    .next()
+                    .map_or(1, char::len_utf8);
+                at = found.start() + skip;
             }
         }
+
+        text.edit(edits);
     }
+}
 
-    input
+fn is_word_start(input: &str, index: usize) -> bool {
+    match input[..index].chars().next_back() {
+        Some(previous) => !(previous.is_alphanumeric() || previous == '_'),
+        None => true,
+    }
 }
 
 #[cfg(test)]
@@ -121,47 +507,183 @@ mod as_tests {
     #[test]
     fn to_test() {
         assert_eq!(
-            to(String::from("ғозал ҒОЗАЛ Ғозал гелий"), "lat"),
+            to(String::from("ғозал ҒОЗАЛ Ғозал гелий"), Alphabet::Latin),
             String::from("g‘ozal GʼOZAL Gʼozal geliy")
         );
         assert_eq!(
-            to(String::from("g'ozal G'OZAL G'ozal geliy"), "cyr"),
+            to(
+                String::from("g'ozal G'OZAL G'ozal geliy"),
+                Alphabet::Cyrillic
+            ),
             String::from("ғозал ҒОЗАЛ Ғозал гелий")
         );
 
         // test TO_LATIN
         assert_eq!(
-            to(String::from("қаерда қаёрда ёрда"), "lat"),
+            to(String::from("қаерда қаёрда ёрда"), Alphabet::Latin),
             String::from("qayerda qayorda yorda")
         );
         assert_eq!(
             to(
                 String::from("13 январ 2021 йил bnuqtai nazar nuqtai nazar"),
-                "lat"
+                Alphabet::Latin
             ),
             String::from("13-yanvar 2021-yil bnuqtai nazar nuqtayi nazar")
         );
         assert_eq!(
-            to(String::from("аца баер Ша Ë Ёш Ë Чуст Юрт шу гўзал"), "lat"),
+            to(
+                String::from("аца баер Ша Ë Ёш Ë Чуст Юрт шу гўзал"),
+                Alphabet::Latin
+            ),
             String::from("atsa bayer Sha Yo Yosh Yo Chust Yurt shu go‘zal")
         );
 
         // test PRE_TO_LATIN
         assert_eq!(
-            to(String::from("«дзнъю» \"а\" ДЗНЪЮ"), "lat"),
+            to(String::from("«дзнъю» \"а\" ДЗНЪЮ"), Alphabet::Latin),
             String::from("“dznyu” “a” DZNYU")
         );
 
         //test TO_CYR
-        assert_eq!(to(String::from("“a MЎJ"), "cyr"), String::from("«а МЎЪЖ"));
+        assert_eq!(
+            to(String::from("“a MЎJ"), Alphabet::Cyrillic),
+            String::from("«а МЎЪЖ")
+        );
 
         // test TO_CYR_SUB
-        assert_eq!(to(String::from("ʼA Eʼ"), "cyr"), String::from("ЪА ЭЪ"));
+        assert_eq!(
+            to(String::from("ʼA Eʼ"), Alphabet::Cyrillic),
+            String::from("ЪА ЭЪ")
+        );
 
         // test TO_CYR_CORRECT
         assert_eq!(
-            to(String::from("аE аЯна-даа"), "cyr"),
+            to(String::from("аE аЯна-даа"), Alphabet::Cyrillic),
             String::from("аЭ аЯнадаа")
         );
     }
+
+    #[test]
+    fn transliterator_test() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Transliterator>();
+
+        let transliterator = Transliterator::new();
+        let latin = "g'ozal G'OZAL G'ozal geliy elektrodvigatel Fransiya";
+
+        assert_eq!(
+            transliterator.to_cyrillic(latin),
+            to_cyrillic(latin.to_string())
+        );
+        assert_eq!(
+            transliterator.to_latin("ғозал ҒОЗАЛ Ғозал гелий"),
+            to_latin(String::from("ғозал ҒОЗАЛ Ғозал гелий"))
+        );
+        assert_eq!(
+            transliterator.to(latin, Alphabet::Cyrillic),
+            to(latin.to_string(), Alphabet::Cyrillic)
+        );
+    }
+
+    #[test]
+    fn to_aligned_test() {
+        let transliterator = Transliterator::new();
+        let texts = [
+            ("G'ozal shahar, elektrodvigatellar \"yangi\" nyan@mail.uz", Alphabet::Cyrillic),
+            ("Ғозал шаҳар, «янги» электродвигателлар 13 январ", Alphabet::Latin),
+            ("", Alphabet::Latin),
+        ];
+
+        for (source, alphabet) in texts {
+            // the text is the same with and without keeping track of the alignment
+            let aligned = transliterator.to_aligned(source, alphabet);
+            assert_eq!(aligned.text, transliterator.to(source, alphabet));
+
+            // alignments are contiguous and cover both texts
+            let mut source_end = 0;
+            let mut target_end = 0;
+            for a in &aligned.alignment {
+                assert_eq!(a.source.start, source_end);
+                assert_eq!(a.target.start, target_end);
+                source_end = a.source.end;
+                target_end = a.target.end;
+            }
+            assert_eq!(source_end, source.len());
+            assert_eq!(target_end, aligned.text.len());
+        }
+
+        let source = "shahar nyan@mail.uz";
+        let aligned = transliterator.to_aligned(source, Alphabet::Cyrillic);
+        let email = aligned.to_target(7..source.len());
+        assert_eq!(aligned.to_source(email.clone()), 7..source.len());
+        assert_eq!(aligned.to_target(0..6), 0..10);
+    }
+
+    #[test]
+    fn with_dictionary_test() {
+        let dictionary = TransliterationDictionary::from_tsv(
+            "Payme\tПейми\nPay\tПэй\nG'ijduvon\tҒиждувон\t1\nUzCard\tУзКард",
+        )
+        .unwrap();
+        let transliterator = Transliterator::with_dictionary(&dictionary);
+
+        // the dictionary wins over the rules, the suffix is transliterated as usual
+        assert_eq!(
+            transliterator.to_cyrillic("Payme orqali to‘lov, PAYMEga"),
+            "Пейми орқали тўлов, ПЕЙМИга"
+        );
+        assert_eq!(transliterator.to_cyrillic("Pay"), "Пэй");
+        assert_eq!(
+            transliterator.to_cyrillic("G‘ijduvon UzCard"),
+            "Ғиждувон УзКард"
+        );
+        // apostrophes are normalized the same way as in the rest of the text
+        assert_eq!(transliterator.to_latin("Ғиждувонда УзКард"), "Gʼijduvonda UzCard");
+
+        // only from the beginning of a word
+        assert_eq!(transliterator.to_cyrillic("repayme"), "репайме");
+
+        // the built-in tables are still applied
+        assert_eq!(
+            transliterator.to_cyrillic("elektrodvigatel"),
+            to_cyrillic(String::from("elektrodvigatel"))
+        );
+
+        let aligned = transliterator.to_aligned("Payme", Alphabet::Cyrillic);
+        assert_eq!(aligned.text, "Пейми");
+        assert_eq!(aligned.to_target(0..5), 0..10);
+    }
+
+    #[test]
+    fn retransliterate_test() {
+        let retranslit = Retransliteration::new(prelude::RETRANSLIT);
+        let apply = |input: &str| {
+            let mut text = TrackedText::new(input);
+            retranslit.apply(&mut text);
+            text.into_string()
+        };
+
+        // only from the beginning of a word, in the case of the matched word
+        assert_eq!(
+            apply("elektrodvigatellar va aviadvigatel"),
+            "электродвигател[ь]lar va авиадвигател[ь]"
+        );
+        assert_eq!(
+            apply("superelektrodvigatel"),
+            "superelektrodvigatel"
+        );
+        assert_eq!(
+            apply("Fransiya FRANSIYA fransiya FrAnsiya"),
+            "Франция ФРАНЦИЯ франция FrAnsiya"
+        );
+        assert_eq!(
+            apply("Elektrodvigatel ELEKTRODVIGATEL"),
+            "Электродвигател[ь] ЭЛЕКТРОДВИГАТЕЛ[Ь]"
+        );
+        assert_eq!(
+            apply("Nyu-York NYU-YORK Nyu-york"),
+            "Нью-Йорк НЬЮ-ЙОРК Нью-йорк"
+        );
+        assert_eq!(apply(""), "");
+    }
 }
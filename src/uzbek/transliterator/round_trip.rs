@@ -0,0 +1,311 @@
+//! Checking which words do not survive transliteration there and back.
+use super::Transliterator;
+use crate::uzbek::alphabet::APOSTROPHES;
+use crate::uzbek::Alphabet;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+/// Reason a word changed after a round trip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundTripIssue {
+    /// Only apostrophes changed, `G'OZAL` comes back as `GʼOZAL`.
+    Apostrophe,
+    /// Only the case of letters changed.
+    Case,
+    /// Soft sign `ь` was lost or added.
+    SoftSign,
+    /// `ц` is written as `ts` or `s` depending on its position.
+    Ts,
+    /// Iotated vowels `е`, `ё`, `ю`, `я` against `ye`, `yo`, `yu`, `ya`, or `е` against `э`.
+    Iotated,
+    /// Only quotes, hyphens or other punctuation changed.
+    Punctuation,
+    /// A rule rewrote the space next to the word, `uzoqlarga yetib` comes back
+    /// as `uzoqlargayetib` as `TO_LATIN` drops the space before `е`.
+    Rule,
+    /// Anything else.
+    Other,
+}
+
+impl RoundTripIssue {
+    /// Short name of the issue, the same one used when (de)serializing.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoundTripIssue::Apostrophe => "apostrophe",
+            RoundTripIssue::Case => "case",
+            RoundTripIssue::SoftSign => "soft_sign",
+            RoundTripIssue::Ts => "ts",
+            RoundTripIssue::Iotated => "iotated",
+            RoundTripIssue::Punctuation => "punctuation",
+            RoundTripIssue::Rule => "rule",
+            RoundTripIssue::Other => "other",
+        }
+    }
+}
+
+impl Display for RoundTripIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Word of the source text that changed after a round trip.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundTripDifference {
+    /// The word, or several words if a rule rewrote them at once.
+    pub word: String,
+    /// Byte range of the word in the source text.
+    pub span: Range<usize>,
+    /// The word in the other alphabet.
+    pub transliterated: String,
+    /// The word transliterated back into the source alphabet.
+    pub round_trip: String,
+    pub issue: RoundTripIssue,
+}
+
+/// Result of [`round_trip_report`](super::round_trip_report).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundTripReport {
+    /// The text in the other alphabet.
+    pub transliterated: String,
+    /// The text transliterated back into the source alphabet.
+    pub round_trip: String,
+    pub differences: Vec<RoundTripDifference>,
+}
+
+impl RoundTripReport {
+    /// Whether every word came back unchanged.
+    pub fn is_lossless(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+impl Transliterator {
+    /// Transliterates text written in the given alphabet into
+    /// the other one and back, reporting every word that changed.
+    pub fn round_trip_report(&self, text: &str, alphabet: Alphabet) -> RoundTripReport {
+        let other = match alphabet {
+            Alphabet::Latin => Alphabet::Cyrillic,
+            Alphabet::Cyrillic => Alphabet::Latin,
+        };
+
+        let forward = self.to_aligned(text, other);
+        let back = self.to_aligned(&forward.text, alphabet);
+        let round_trip = |span: Range<usize>| back.to_target(forward.to_target(span));
+
+        // (source span, round trip span), parts rewritten by a single rule are grouped
+        let mut groups: Vec<(Range<usize>, Range<usize>)> = vec![];
+        for alignment in &forward.alignment {
+            let target = back.to_target(alignment.target.clone());
+
+            match groups.last_mut() {
+                Some((source, previous)) if target.start < previous.end => {
+                    source.end = alignment.source.end;
+                    previous.end = previous.end.max(target.end);
+                }
+                _ => groups.push((alignment.source.clone(), target)),
+            }
+        }
+
+        // changed parts widened to whole words, a changed separator takes both neighbours
+        let mut changed: Vec<Range<usize>> = vec![];
+        for (source, target) in groups {
+            if text[source.clone()] == back.text[target] {
+                continue;
+            }
+
+            let span = widen(text, source);
+            match changed.last_mut() {
+                Some(previous) if span.start <= previous.end => previous.end = span.end,
+                _ => changed.push(span),
+            }
+        }
+
+        let differences = changed
+            .into_iter()
+            .filter_map(|span| {
+                let span = trim(text, span);
+                let word = &text[span.clone()];
+                let result = &back.text[trim(&back.text, round_trip(span.clone()))];
+
+                // quotes around the word are not a part of it
+                if word == result {
+                    return None;
+                }
+
+                Some(RoundTripDifference {
+                    word: word.to_string(),
+                    transliterated: forward.text
+                        [trim(&forward.text, forward.to_target(span.clone()))]
+                    .to_string(),
+                    round_trip: result.to_string(),
+                    issue: classify(word, result),
+                    span,
+                })
+            })
+            .collect();
+
+        RoundTripReport {
+            transliterated: forward.text,
+            round_trip: back.text,
+            differences,
+        }
+    }
+}
+
+fn is_word_char(char: char) -> bool {
+    char.is_alphabetic() || APOSTROPHES.contains(&char)
+}
+
+/// Widens the range to whole words, apostrophes (as in `o‘`) are a part of a word.
+fn widen(text: &str, range: Range<usize>) -> Range<usize> {
+    let start = text[..range.start]
+        .char_indices()
+        .rev()
+        .take_while(|(_, char)| is_word_char(*char))
+        .last()
+        .map_or(range.start, |(index, _)| index);
+    let end = text[range.end..]
+        .char_indices()
+        .take_while(|(_, char)| is_word_char(*char))
+        .last()
+        .map_or(range.end, |(index, char)| {
+            range.end + index + char.len_utf8()
+        });
+
+    start..end
+}
+
+/// Narrows the range to exclude punctuation around the words.
+fn trim(text: &str, range: Range<usize>) -> Range<usize> {
+    let slice = &text[range.clone()];
+    let trimmed = slice.trim_start_matches(|char| !is_word_char(char));
+    let start = range.start + (slice.len() - trimmed.len());
+    let trimmed = trimmed.trim_end_matches(|char| !is_word_char(char));
+
+    start..start + trimmed.len()
+}
+
+fn classify(word: &str, round_trip: &str) -> RoundTripIssue {
+    // any apostrophe, doubled or not
+    let unify = |text: &str| -> String {
+        let mut unified = String::with_capacity(text.len());
+        for char in text.chars() {
+            if !APOSTROPHES.contains(&char) {
+                unified.push(char);
+            } else if !unified.ends_with('\'') {
+                unified.push('\'');
+            }
+        }
+        unified
+    };
+
+    let (word, round_trip) = (unify(word), unify(round_trip));
+    if word == round_trip {
+        return RoundTripIssue::Apostrophe;
+    }
+
+    let (word, round_trip) = (word.to_lowercase(), round_trip.to_lowercase());
+    if word == round_trip {
+        return RoundTripIssue::Case;
+    }
+
+    // look only at the part in between the common prefix and suffix
+    let word: Vec<char> = word.chars().collect();
+    let round_trip: Vec<char> = round_trip.chars().collect();
+
+    let prefix = word
+        .iter()
+        .zip(&round_trip)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = word[prefix..]
+        .iter()
+        .rev()
+        .zip(round_trip[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let changed: Vec<char> = word[prefix..word.len() - suffix]
+        .iter()
+        .chain(&round_trip[prefix..round_trip.len() - suffix])
+        .copied()
+        .collect();
+
+    let is_ts = |char: &char| "tsтс".contains(*char);
+
+    if changed.contains(&'ь') {
+        RoundTripIssue::SoftSign
+    } else if changed.contains(&'ц')
+        || (changed.iter().any(|c| "tт".contains(*c)) && changed.iter().all(is_ts))
+    {
+        RoundTripIssue::Ts
+    } else if changed.iter().all(|char| "yeouaеёюяэ".contains(*char)) {
+        RoundTripIssue::Iotated
+    } else if changed.iter().any(|char| char.is_whitespace()) {
+        RoundTripIssue::Rule
+    } else if changed.iter().all(|char| !char.is_alphanumeric()) {
+        RoundTripIssue::Punctuation
+    } else {
+        RoundTripIssue::Other
+    }
+}
+
+#[cfg(test)]
+mod as_tests {
+    use super::*;
+
+    #[test]
+    fn classify_test() {
+        let cases = [
+            ("G'OZAL", "GʼOZAL", RoundTripIssue::Apostrophe),
+            ("SALOM", "Salom", RoundTripIssue::Case),
+            ("январь", "январ", RoundTripIssue::SoftSign),
+            ("цирк", "сирк", RoundTripIssue::Ts),
+            ("рецепт", "ретсепт", RoundTripIssue::Ts),
+            ("поэт", "поет", RoundTripIssue::Iotated),
+            ("Bobur-u", "Boburu", RoundTripIssue::Punctuation),
+            ("uzoqlarga yetib", "uzoqlargayetib", RoundTripIssue::Rule),
+            ("abc", "abd", RoundTripIssue::Other),
+        ];
+
+        for (word, round_trip, issue) in cases {
+            assert_eq!(classify(word, round_trip), issue, "{word} -> {round_trip}");
+        }
+    }
+
+    #[test]
+    fn round_trip_report_test() {
+        let transliterator = Transliterator::new();
+
+        let report = transliterator.round_trip_report("g‘ozal shahar", Alphabet::Latin);
+        assert!(report.is_lossless());
+        assert_eq!(report.transliterated, "ғозал шаҳар");
+
+        let report = transliterator.round_trip_report("Salom, G'OZAL shahar!", Alphabet::Latin);
+        assert_eq!(report.differences.len(), 1);
+        let difference = &report.differences[0];
+        assert_eq!(difference.word, "G'OZAL");
+        assert_eq!(difference.span, 7..13);
+        assert_eq!(difference.transliterated, "ҒОЗАЛ");
+        assert_eq!(difference.round_trip, "GʼOZAL");
+        assert_eq!(difference.issue, RoundTripIssue::Apostrophe);
+
+        let report = transliterator.round_trip_report("Гоголь куни, шаҳар", Alphabet::Cyrillic);
+        let issues: Vec<(&str, RoundTripIssue)> = report
+            .differences
+            .iter()
+            .map(|difference| (difference.word.as_str(), difference.issue))
+            .collect();
+        assert_eq!(issues, vec![("Гоголь", RoundTripIssue::SoftSign)]);
+
+        // the hyphen is lost in between the words
+        let report = transliterator.round_trip_report("Bobur-u Navoiy", Alphabet::Latin);
+        assert_eq!(report.differences.len(), 1);
+        assert_eq!(report.differences[0].word, "Bobur-u");
+        assert_eq!(report.differences[0].round_trip, "Boburu");
+        assert_eq!(report.differences[0].issue, RoundTripIssue::Punctuation);
+    }
+}
@@ -0,0 +1,161 @@
+//! Properties of `alphabetic::sort` checked on generated word lists.
+//!
+//! The reference is a copy of the bubble sort words were sorted with before the
+//! sort keys. It is only trusted on the words it sorted right, the intended
+//! differences from it are listed in `intended_differences`.
+mod baseline;
+mod common;
+
+use common::Generator;
+use korrektor::error::KorrektorError;
+use korrektor::uzbek::alphabetic::{self, SortOptions, UzbekCollator};
+
+const CASES: usize = 300;
+
+const LETTERS: &[&str] = &[
+    "a", "b", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t",
+    "u", "v", "x", "y", "z", "o‘", "g‘", "sh", "ch", "A", "O‘", "G‘", "Sh", "SH", "Ch", "CH", "Z",
+    "а", "б", "ч", "ш", "ў", "қ", "ғ", "ҳ", "я", "Ў", "Ч", "Ғ",
+];
+
+fn words(generator: &mut Generator) -> Vec<String> {
+    (0..generator.below(30) + 1)
+        .map(|_| {
+            (0..generator.below(6) + 1)
+                .map(|_| generator.pick(LETTERS))
+                .collect()
+        })
+        .collect()
+}
+
+// letters the old sort has right: no SH and CH, no в, ю and я
+const BASELINE_LETTERS: &[&str] = &[
+    "a", "b", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t",
+    "u", "v", "x", "y", "z", "o‘", "g‘", "sh", "ch", "A", "B", "O", "O‘", "G‘", "Sh", "Ch", "Z",
+    "а", "б", "ч", "ш", "ў", "қ", "ғ", "ҳ", "ё", "Ў", "Ч", "Ғ",
+];
+
+// the old comparison of two sortable words is right unless they differ only at the
+// last letter of the shorter one, which it skips, or it runs past the end of one
+fn baseline_compares_right(a: &str, b: &str) -> bool {
+    let length = a.len().min(b.len()) - 1;
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    match a.iter().zip(&b).position(|(a, b)| a != b) {
+        Some(index) => index < length,
+        None => length <= a.len().min(b.len()),
+    }
+}
+
+// word lists the old sort has right: words it would compare wrong with one
+// of the words before are left out
+fn baseline_words(generator: &mut Generator) -> Vec<String> {
+    let mut words: Vec<String> = vec![];
+    let mut sortable: Vec<String> = vec![];
+
+    for _ in 0..generator.below(30) + 1 {
+        let word: String = (0..generator.below(6) + 1)
+            .map(|_| generator.pick(BASELINE_LETTERS))
+            .collect();
+        let word_sortable = baseline::alphabetic::to_sortable(word.clone());
+
+        let right = sortable.iter().all(|other| {
+            baseline_compares_right(other, &word_sortable)
+                && baseline_compares_right(&word_sortable, other)
+        });
+        if right {
+            words.push(word);
+            sortable.push(word_sortable);
+        }
+    }
+
+    words
+}
+
+#[test]
+fn sort_matches_baseline() {
+    let mut generator = Generator(0x4f1b_bcdc_bfa5_3e0b);
+    let strict = SortOptions {
+        strict: true,
+        ..SortOptions::default()
+    };
+
+    for _ in 0..CASES {
+        let text = baseline_words(&mut generator).join(" ");
+        let expected = baseline::alphabetic::sort(&text).unwrap();
+
+        assert_eq!(alphabetic::sort(&text).unwrap(), expected, "{text}");
+        assert_eq!(
+            alphabetic::sort_with(&text, &strict).unwrap(),
+            expected,
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn intended_differences() {
+    // text, the old result and the new one
+    let differences: &[(&str, Result<&str, char>, &str)] = &[
+        // the last letter counts
+        ("ad ab", Ok("ad ab"), "ab ad"),
+        ("olmb olma", Ok("olmb olma"), "olma olmb"),
+        // SH and CH in their own place, after G‘
+        ("SHAHAR G‘OZ", Ok("SHAHAR G‘OZ"), "G‘OZ SHAHAR"),
+        // the cyrillic table has в and ю before я
+        ("вагон гул", Err('в'), "вагон гул"),
+        ("ян юз", Ok("ян юз"), "юз ян"),
+        // numbers and punctuation
+        ("34 12", Err('3'), "12 34"),
+        ("a,b a,d", Err(','), "a,b a,d"),
+    ];
+    // the old sort also panicked on "" and on "ўзбек ўзбекча"
+
+    for (text, old, new) in differences {
+        let result = match baseline::alphabetic::sort(text) {
+            Ok(sorted) => Ok(sorted),
+            Err(KorrektorError::InvalidChar(char)) => Err(char),
+            Err(error) => panic!("{error:?}"),
+        };
+
+        assert_eq!(result, old.map(String::from), "{text}");
+        assert_eq!(alphabetic::sort(text).unwrap(), *new, "{text}");
+    }
+    assert_eq!(alphabetic::sort("").unwrap(), "");
+    assert_eq!(alphabetic::sort("ўзбекча ўзбек").unwrap(), "ўзбек ўзбекча");
+}
+
+#[test]
+fn order_of_input_does_not_matter() {
+    let mut generator = Generator(0x94d0_49bb_1331_11eb);
+
+    for _ in 0..CASES {
+        let mut words = words(&mut generator);
+        let sorted = alphabetic::sort(&words.join(" ")).unwrap();
+
+        generator.shuffle(&mut words);
+        assert_eq!(alphabetic::sort(&words.join(" ")).unwrap(), sorted);
+        assert_eq!(alphabetic::sort(&sorted).unwrap(), sorted);
+    }
+}
+
+#[test]
+fn collation_keys_match_comparison() {
+    let mut generator = Generator(0xbf58_476d_1ce4_e5b9);
+    let collator = UzbekCollator::new();
+
+    for _ in 0..CASES {
+        let words = words(&mut generator);
+
+        for pair in words.windows(2) {
+            assert_eq!(
+                alphabetic::collation_key(&pair[0]).cmp(&alphabetic::collation_key(&pair[1])),
+                collator.compare(&pair[0], &pair[1]),
+                "{} {}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+}
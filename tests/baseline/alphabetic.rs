@@ -0,0 +1,155 @@
+//! `alphabetic::sort` before the sort keys: a bubble sort comparing words
+//! letter by letter by their position in the alphabet table.
+//!
+//! Known problems, kept on purpose:
+//! - the last letter of the shorter word is not compared (`ad ab` stays as it is);
+//! - `SH` and `CH` take the place of `Ğ` and `Š`;
+//! - the cyrillic table misses `в` and has lowercase `я` before `ю`;
+//! - anything but a letter of the table is an error (`12`, `olma,`);
+//! - it panics on empty text and on multi-byte words where one starts with the other.
+use korrektor::error::KorrektorError;
+use korrektor::utils::pcre;
+
+pub const CHAR_ORDER: &[&str] = &[
+    "ê", "a", "b", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s",
+    "t", "u", "v", "x", "y", "z", "ŏ", "ğ", "š", "č", "Ê", "A", "B", "D", "E", "F", "G", "H", "I",
+    "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S", "T", "U", "V", "X", "Y", "Z", "Ŏ", "Ğ", "Š",
+    "Č", "а", "б", "д", "г", "д", "е", "ё", "ж", "з", "и", "й", "к", "л", "м", "н", "о", "п", "р",
+    "с", "т", "у", "ф", "х", "ц", "ч", "ш", "ъ", "ь", "э", "я", "ю", "ў", "қ", "ғ", "ҳ", "А", "Б",
+    "Д", "Г", "Д", "Е", "Ё", "Ж", "З", "И", "Й", "К", "Л", "М", "Н", "О", "П", "Р", "С", "Т", "У",
+    "Ф", "Х", "Ц", "Ч", "Ш", "Ъ", "Ь", "Э", "Ю", "Я", "Ў", "Қ", "Ғ", "Ҳ",
+];
+
+const TO_SORT: &[(&str, &str)] = &[
+    ("G[ʻʼ'‘’‛′ʽ`]", "Ğ"),
+    ("g[ʻʼ'‘’‛′ʽ`]", "ğ"),
+    ("O[ʻʼ'‘’‛′ʽ`]", "Ŏ"),
+    ("o[ʻʼ'‘’‛′ʽ`]", "ŏ"),
+    ("ʻ|ʼ|'|‘|’|‛|′|ʽ|`", "ʼ"),
+    ("Sh", "Š"),
+    ("SH", "Ö"),
+    ("sh", "š"),
+    ("Ch", "Č"),
+    ("CH", "Ü"),
+    ("ch", "č"),
+];
+
+const FROM_SORT: &[(&str, &str)] = &[
+    ("Ğ", "G‘"),
+    ("ğ", "g‘"),
+    ("Ŏ", "O‘"),
+    ("ŏ", "o‘"),
+    ("Š", "Sh"),
+    ("Ö", "SH"),
+    ("š", "sh"),
+    ("Č", "Ch"),
+    ("Ü", "CH"),
+    ("č", "ch"),
+];
+
+pub fn sort(text: &str) -> Result<String, KorrektorError> {
+    // replace complex symbols in text with sortable alternatives
+    let sortable = &to_sortable(text.to_string());
+
+    let sorted_intermediate = sort_sortable(sortable)?;
+
+    // replace sortable alternatives with original values after sorting
+    Ok(from_sortable(sorted_intermediate))
+}
+
+pub fn to_sortable(text: String) -> String {
+    pcre::replace_pairs(&text, TO_SORT)
+}
+
+fn from_sortable(text: String) -> String {
+    pcre::replace_pairs(&text, FROM_SORT)
+}
+
+pub fn usort(string1: &str, string2: &str) -> Result<i8, KorrektorError> {
+    let length = std::cmp::min(string1.len() - 1, string2.len() - 1);
+
+    for i in 0..length {
+        let char1 = match string1.chars().nth(i) {
+            Some(char) => char,
+            None => panic!("Error in usort: no char at the index {i} in &str: {string1}"),
+        };
+        let char2 = match string2.chars().nth(i) {
+            Some(char) => char,
+            None => panic!("Error in usort: no char at the index {i} in &str: {string2}"),
+        };
+
+        // get position of characters in the alphabet
+        let value1 = get_value(char1)?;
+        let value2 = get_value(char2)?;
+
+        match value1.cmp(&value2) {
+            std::cmp::Ordering::Less => return Ok(-1),
+            std::cmp::Ordering::Greater => return Ok(1),
+            std::cmp::Ordering::Equal => continue,
+        };
+    }
+
+    match (string1.len()).cmp(&string2.len()) {
+        std::cmp::Ordering::Less => Ok(-1),
+        std::cmp::Ordering::Greater => Ok(1),
+        std::cmp::Ordering::Equal => Ok(0),
+    }
+}
+
+fn sort_sortable(text: &str) -> Result<String, KorrektorError> {
+    let mut sortable: Vec<&str> = text.split_whitespace().collect();
+    let mut len = sortable.len();
+
+    let mut sorted = false;
+    while !sorted {
+        sorted = true;
+        for i in 0..len - 1 {
+            match usort(sortable[i], sortable[i + 1])? {
+                1 => {
+                    sortable.swap(i, i + 1);
+                    sorted = false;
+                }
+                _ => continue,
+            }
+        }
+        len -= 1;
+    }
+
+    let mut result = String::new();
+    for word in sortable {
+        result.push_str(word);
+        result.push(' ');
+    }
+
+    Ok(result.trim().to_string())
+}
+
+fn is_exceptioned(value: char) -> bool {
+    if value == 'Ö' || value == 'Ü' {
+        return true;
+    }
+
+    false
+}
+
+fn get_exceptioned_value(value: char) -> usize {
+    if value == 'Ö' {
+        return 55;
+    }
+    if value == 'Ü' {
+        return 56;
+    }
+
+    0
+}
+
+fn get_value(value: char) -> Result<usize, KorrektorError> {
+    if is_exceptioned(value) {
+        Ok(get_exceptioned_value(value))
+    } else {
+        match CHAR_ORDER.iter().position(|&r| r == value.to_string()) {
+            Some(num) => Ok(num),
+            None => Err(KorrektorError::InvalidChar(value)),
+        }
+    }
+}
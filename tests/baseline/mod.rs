@@ -0,0 +1,6 @@
+//! Copies of implementations that were replaced, kept as they were to check
+//! and measure the new ones against them in tests and benchmarks.
+#![allow(dead_code)]
+
+pub mod alphabetic;
+pub mod transliterator;
@@ -0,0 +1,86 @@
+//! `transliterator::to_cyrillic` and `to_latin` before the compiled rules: every
+//! call compiles all rule tables again, a regex per word of `RETRANSLIT`.
+//!
+//! The rule tables are the ones of the crate, the baseline used the same ones.
+use inflector::Inflector;
+use korrektor::utils::pcre as ipcre;
+
+#[path = "../../src/uzbek/transliterator/prelude.rs"]
+mod prelude;
+
+pub fn to_cyrillic(text: String) -> String {
+    let mut input = text;
+
+    input = ipcre::replace_pairs(&input, prelude::TO_CYR);
+
+    let re = regex::Regex::new("([a-zA-Zа-яА-ЯўқғҳЎҚҒҲʼʻ?!.0-9])(“|”|\")").unwrap();
+    input = re.replace_all(&input, "$1»").as_ref().to_string();
+
+    input = ipcre::replace_pairs(&input, prelude::PRE_RETRANSLIT);
+
+    // replace constant words from constants::RETRANSLIT
+    input = retransliterate(&input);
+
+    input = ipcre::replace_pairs(&input, prelude::POST_RETRANSLIT);
+    input = ipcre::replace_pairs(&input, prelude::TO_CYR_SUB);
+    input = replace_letters(&input, prelude::LATIN_L2C, prelude::CYRILLIC_L2C);
+    input = ipcre::replace_pairs(&input, prelude::TO_CYR_CORRECT);
+
+    input
+}
+
+pub fn to_latin(text: String) -> String {
+    let mut input = text;
+
+    input = ipcre::replace_pairs(&input, prelude::PRE_TO_LATIN);
+
+    input = replace_letters(&input, prelude::CYRILLIC_C2L, prelude::LATIN_C2L);
+
+    input = ipcre::replace_pairs(&input, prelude::TO_LATIN);
+
+    input
+}
+
+fn replace_letters(input: &str, input_alphabet: &[&str], output_alphabet: &[&str]) -> String {
+    let mut input = input.to_string();
+
+    let mut i = 0;
+    while i < input_alphabet.len() {
+        let re = regex::Regex::new(input_alphabet[i]).unwrap();
+        input = re
+            .replace_all(&input, output_alphabet[i])
+            .as_ref()
+            .to_string();
+        i += 1;
+    }
+
+    input
+}
+
+fn retransliterate(input: &str) -> String {
+    let mut input = input.to_string();
+
+    for (pattern, replacement) in prelude::RETRANSLIT {
+        let wrapped = String::from("\\b") + pattern;
+
+        let text = input.clone();
+        let re = regex::Regex::new(&wrapped).unwrap();
+        for cap in re.captures_iter(&text) {
+            if cap[0] == cap[0].to_string().to_lowercase() {
+                input = re.replace(&input, *replacement).parse().unwrap();
+            } else if cap[0] == cap[0].to_string().to_uppercase() {
+                input = re
+                    .replace(&input, replacement.to_uppercase())
+                    .parse()
+                    .unwrap();
+            } else if cap[0] == cap[0].to_title_case() {
+                input = re
+                    .replace(&input, replacement.to_title_case())
+                    .parse()
+                    .unwrap();
+            }
+        }
+    }
+
+    input
+}
@@ -0,0 +1,34 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+// xorshift, good enough to generate text and keeps the tests reproducible
+pub struct Generator(pub u64);
+
+impl Generator {
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+
+    pub fn pick<T: Clone>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len())].clone()
+    }
+
+    pub fn text(&mut self, chars: &[char], max_len: usize) -> String {
+        (0..self.below(max_len) + 1)
+            .map(|_| self.pick(chars))
+            .collect()
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
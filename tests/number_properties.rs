@@ -0,0 +1,92 @@
+//! Properties of `numbers_to_word` checked on generated text.
+//!
+//! Text is generated from a fixed seed, a failing case is printed
+//! along with the assertion and can be reproduced as it is.
+mod common;
+
+use common::Generator;
+use korrektor::uzbek::number;
+use korrektor::uzbek::Alphabet;
+
+const CASES: usize = 500;
+
+#[test]
+fn text_without_digits_is_preserved() {
+    let chars: Vec<char> = "aAbdgGoOqxyzsuSh ʻʼ'‘’`-.,:;!?%$€()〈〉\n\tҳқғўЎЯжчIVXLCDM"
+        .chars()
+        .collect();
+    let mut generator = Generator(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..CASES {
+        let text = generator.text(&chars, 40);
+
+        assert_eq!(number::numbers_to_word(&text, None).unwrap(), text);
+    }
+}
+
+#[test]
+fn only_numbers_are_replaced() {
+    // no letters of words with a meaning after a number (yil, so‘m, months)
+    let letters: Vec<char> = "abdefhklnoprstuzғқ‘ʼ'〈〉".chars().collect();
+    let mut generator = Generator(0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..CASES {
+        let mut text = vec![];
+        let mut expected = vec![];
+
+        for _ in 0..generator.below(8) + 1 {
+            if generator.below(2) == 0 {
+                let word = generator.text(&letters, 8);
+                text.push(word.clone());
+                expected.push(word);
+            } else {
+                // small numbers are often a part of other ones: 1 and 12
+                let number = match generator.below(3) {
+                    0 => generator.below(20).to_string(),
+                    1 => format!("-{}", generator.below(1000)),
+                    _ => generator.next().to_string(),
+                };
+                expected.push(number::integer_to_word(&number, Alphabet::Latin).unwrap());
+                text.push(number);
+            }
+        }
+
+        let text = text.join(", ");
+        assert_eq!(
+            number::numbers_to_word(&text, Some(Alphabet::Latin)).unwrap(),
+            expected.join(", "),
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn protected_text_is_preserved() {
+    let mut generator = Generator(0xd1b5_4a32_d192_ed03);
+
+    for _ in 0..CASES {
+        let phone = format!(
+            "998{:02} {:03} {:02} {:02}",
+            generator.below(100),
+            generator.below(1000),
+            generator.below(100),
+            generator.below(100)
+        );
+        let ip = format!(
+            "{}.{}.{}.{}",
+            generator.below(256),
+            generator.below(256),
+            generator.below(256),
+            generator.below(256)
+        );
+        let number = generator.below(100);
+
+        let text = format!("{phone} {number} {ip}, {number}");
+        let words = number::integer_to_word(&number.to_string(), Alphabet::Latin).unwrap();
+
+        assert_eq!(
+            number::numbers_to_word(&text, None).unwrap(),
+            format!("{phone} {words} {ip}, {words}")
+        );
+    }
+}
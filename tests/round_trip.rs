@@ -0,0 +1,78 @@
+//! Round trip transliteration of a corpus checked against the known differences.
+//!
+//! Every word of `tests/data/round_trip/{latin,cyrillic}.txt` that does not survive
+//! transliteration there and back is listed in `tests/data/round_trip/expected.tsv`.
+//! After an intended change of the rule tables (`RETRANSLIT` and others) review
+//! the failure and rewrite the list with `KORREKTOR_BLESS=1 cargo test --test round_trip`.
+use korrektor::uzbek::transliterator;
+use korrektor::uzbek::Alphabet;
+use std::path::PathBuf;
+
+fn data(file: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/data/round_trip")
+        .join(file)
+}
+
+fn differences(file: &str, alphabet: Alphabet) -> Vec<String> {
+    let corpus = std::fs::read_to_string(data(file)).unwrap();
+    let mut differences = vec![];
+
+    for (number, line) in corpus.lines().enumerate() {
+        if line.starts_with('#') {
+            continue;
+        }
+
+        for difference in transliterator::round_trip_report(line, alphabet).differences {
+            differences.push(format!(
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                alphabet,
+                number + 1,
+                difference.issue,
+                difference.word,
+                difference.transliterated,
+                difference.round_trip
+            ));
+        }
+    }
+
+    differences
+}
+
+#[test]
+fn round_trip_corpus_test() {
+    let mut actual = differences("latin.txt", Alphabet::Latin);
+    actual.extend(differences("cyrillic.txt", Alphabet::Cyrillic));
+
+    let path = data("expected.tsv");
+    if std::env::var_os("KORREKTOR_BLESS").is_some() {
+        let header = "# alphabet\tline\tissue\tword\ttransliterated\tround trip";
+        std::fs::write(&path, format!("{header}\n{}\n", actual.join("\n"))).unwrap();
+        return;
+    }
+
+    let expected: Vec<String> = std::fs::read_to_string(&path)
+        .unwrap()
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(String::from)
+        .collect();
+
+    let fixed: Vec<&String> = expected.iter().filter(|d| !actual.contains(d)).collect();
+    let broken: Vec<&String> = actual.iter().filter(|d| !expected.contains(d)).collect();
+
+    assert!(
+        fixed.is_empty() && broken.is_empty(),
+        "round trip differences changed\n\nno longer differ:\n{}\n\nnew differences:\n{}\n",
+        fixed
+            .iter()
+            .map(|d| d.as_str())
+            .collect::<Vec<&str>>()
+            .join("\n"),
+        broken
+            .iter()
+            .map(|d| d.as_str())
+            .collect::<Vec<&str>>()
+            .join("\n"),
+    );
+}